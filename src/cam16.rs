@@ -0,0 +1,196 @@
+use crate::xyz::mul;
+use crate::{LinearRGB, WhitePoint, RGB, XYZ};
+
+/// A color in CAM16-UCS, the uniform color space derived from the CAM16
+/// color appearance model.
+///
+/// Conversions assume the standard sRGB viewing conditions: D65 white,
+/// adapting luminance of 64/π·0.2 cd/m², 20% background and an average
+/// surround.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cam16Ucs {
+    /// Lightness J′, nominally in `[0, 100]`.
+    pub j: f64,
+    pub a: f64,
+    pub b: f64,
+}
+
+const M16: [[f64; 3]; 3] = [
+    [0.401288, 0.650173, -0.051461],
+    [-0.250268, 1.204414, 0.045854],
+    [-0.002079, 0.048952, 0.953127],
+];
+
+const M16_INV: [[f64; 3]; 3] = [
+    [1.8620678550872327, -1.0112546305316843, 0.14918677544445175],
+    [0.38752654323613717, 0.6214474419314753, -0.008973985167612518],
+    [-0.015841498849333856, -0.03412293802851557, 1.0499644368778496],
+];
+
+/// Viewing-condition dependent parameters of CAM16.
+struct Viewing {
+    d_rgb: [f64; 3],
+    f_l: f64,
+    n: f64,
+    z: f64,
+    n_bb: f64,
+    a_w: f64,
+    c: f64,
+    n_c: f64,
+}
+
+impl Viewing {
+    fn srgb() -> Self {
+        let w = WhitePoint::D65.xyz();
+        let white = [w.x * 100.0, w.y * 100.0, w.z * 100.0];
+        let l_a = 64.0 / std::f64::consts::PI * 0.2;
+        let y_b = 20.0;
+        let (f, c, n_c) = (1.0, 0.69, 1.0);
+
+        let rgb_w = mul(&M16, white);
+        let d = (f * (1.0 - (1.0 / 3.6) * ((-l_a - 42.0) / 92.0).exp())).clamp(0.0, 1.0);
+        let d_rgb = rgb_w.map(|c| d * white[1] / c + 1.0 - d);
+
+        let k = 1.0 / (5.0 * l_a + 1.0);
+        let k4 = k.powi(4);
+        let f_l = 0.2 * k4 * (5.0 * l_a) + 0.1 * (1.0 - k4).powi(2) * (5.0 * l_a).cbrt();
+
+        let n = y_b / white[1];
+        let z = 1.48 + n.sqrt();
+        let n_bb = 0.725 * n.powf(-0.2);
+
+        let rgb_aw = [0, 1, 2].map(|i| adapt(d_rgb[i] * rgb_w[i], f_l));
+        let a_w = (2.0 * rgb_aw[0] + rgb_aw[1] + rgb_aw[2] / 20.0 - 0.305) * n_bb;
+
+        Viewing { d_rgb, f_l, n, z, n_bb, a_w, c, n_c }
+    }
+}
+
+/// The post-adaptation nonlinear cone response compression.
+fn adapt(c: f64, f_l: f64) -> f64 {
+    let x = (f_l * c.abs() / 100.0).powf(0.42);
+    400.0 * c.signum() * x / (x + 27.13) + 0.1
+}
+
+/// Inverse of [`adapt`].
+fn unadapt(c: f64, f_l: f64) -> f64 {
+    let x = c - 0.1;
+    x.signum() * 100.0 / f_l * (27.13 * x.abs() / (400.0 - x.abs())).powf(1.0 / 0.42)
+}
+
+impl Cam16Ucs {
+    /// Creates a new CAM16-UCS color.
+    pub fn new(j: f64, a: f64, b: f64) -> Self {
+        Cam16Ucs { j, a, b }
+    }
+
+    /// Converts D65-relative XYZ (with `Y = 1` for white) to CAM16-UCS.
+    pub fn from_xyz(xyz: XYZ) -> Self {
+        let vc = Viewing::srgb();
+
+        let rgb = mul(&M16, [xyz.x * 100.0, xyz.y * 100.0, xyz.z * 100.0]);
+        let [r_a, g_a, b_a] = [0, 1, 2].map(|i| adapt(vc.d_rgb[i] * rgb[i], vc.f_l));
+
+        let a = r_a - 12.0 * g_a / 11.0 + b_a / 11.0;
+        let b = (r_a + g_a - 2.0 * b_a) / 9.0;
+        let h = b.atan2(a);
+
+        let e_t = 0.25 * ((h + 2.0).cos() + 3.8);
+        let big_a = (2.0 * r_a + g_a + b_a / 20.0 - 0.305) * vc.n_bb;
+        let j = 100.0 * (big_a / vc.a_w).max(0.0).powf(vc.c * vc.z);
+
+        let t = (50000.0 / 13.0 * vc.n_c * vc.n_bb * e_t * a.hypot(b))
+            / (r_a + g_a + 21.0 / 20.0 * b_a);
+        let chroma = t.max(0.0).powf(0.9) * (j / 100.0).sqrt() * (1.64 - 0.29f64.powf(vc.n)).powf(0.73);
+        let m = chroma * vc.f_l.powf(0.25);
+
+        let j_ucs = 1.7 * j / (1.0 + 0.007 * j);
+        let m_ucs = (1.0 + 0.0228 * m).ln() / 0.0228;
+        Cam16Ucs {
+            j: j_ucs,
+            a: m_ucs * h.cos(),
+            b: m_ucs * h.sin(),
+        }
+    }
+
+    /// Converts to D65-relative XYZ (with `Y = 1` for white).
+    pub fn to_xyz(self) -> XYZ {
+        let vc = Viewing::srgb();
+
+        let j = self.j / (1.7 - 0.007 * self.j);
+        let m = ((0.0228 * self.a.hypot(self.b)).exp() - 1.0) / 0.0228;
+        let h = self.b.atan2(self.a);
+        let chroma = m / vc.f_l.powf(0.25);
+
+        let t = if j > 0.0 {
+            (chroma / ((j / 100.0).sqrt() * (1.64 - 0.29f64.powf(vc.n)).powf(0.73))).powf(1.0 / 0.9)
+        } else {
+            0.0
+        };
+        let e_t = 0.25 * ((h + 2.0).cos() + 3.8);
+        let big_a = vc.a_w * (j.max(0.0) / 100.0).powf(1.0 / (vc.c * vc.z));
+
+        let p2 = big_a / vc.n_bb + 0.305;
+        let p3 = 21.0 / 20.0;
+        let (a, b) = if t == 0.0 {
+            (0.0, 0.0)
+        } else {
+            let p1 = 50000.0 / 13.0 * vc.n_c * vc.n_bb * e_t / t;
+            let (sin, cos) = h.sin_cos();
+            if sin.abs() >= cos.abs() {
+                let b = p2 * (2.0 + p3) * (460.0 / 1403.0)
+                    / (p1 / sin + (2.0 + p3) * (220.0 / 1403.0) * (cos / sin) - 27.0 / 1403.0
+                        + p3 * (6300.0 / 1403.0));
+                (b * cos / sin, b)
+            } else {
+                let a = p2 * (2.0 + p3) * (460.0 / 1403.0)
+                    / (p1 / cos + (2.0 + p3) * (220.0 / 1403.0)
+                        - (27.0 / 1403.0 - p3 * (6300.0 / 1403.0)) * (sin / cos));
+                (a, a * sin / cos)
+            }
+        };
+
+        let r_a = (460.0 * p2 + 451.0 * a + 288.0 * b) / 1403.0;
+        let g_a = (460.0 * p2 - 891.0 * a - 261.0 * b) / 1403.0;
+        let b_a = (460.0 * p2 - 220.0 * a - 6300.0 * b) / 1403.0;
+        let rgb = [r_a, g_a, b_a];
+        let rgb = [0, 1, 2].map(|i| unadapt(rgb[i], vc.f_l) / vc.d_rgb[i]);
+
+        let [x, y, z] = mul(&M16_INV, rgb);
+        XYZ::new(x / 100.0, y / 100.0, z / 100.0)
+    }
+
+    /// Converts to linear-light sRGB without clipping.
+    pub fn to_linear(self) -> LinearRGB {
+        self.to_xyz().to_linear()
+    }
+
+    /// Converts to 8-bit sRGB, clipping out-of-gamut values.
+    pub fn to_rgb(self) -> RGB {
+        self.to_linear().to_rgb()
+    }
+
+    /// Euclidean distance, ΔE in CAM16-UCS.
+    pub fn distance(self, other: Cam16Ucs) -> f64 {
+        ((self.j - other.j).powi(2) + (self.a - other.a).powi(2) + (self.b - other.b).powi(2)).sqrt()
+    }
+}
+
+impl RGB {
+    /// Converts to CAM16-UCS under standard sRGB viewing conditions.
+    pub fn to_cam16_ucs(self) -> Cam16Ucs {
+        Cam16Ucs::from_xyz(self.to_xyz())
+    }
+}
+
+impl From<RGB> for Cam16Ucs {
+    fn from(rgb: RGB) -> Self {
+        rgb.to_cam16_ucs()
+    }
+}
+
+impl From<Cam16Ucs> for RGB {
+    fn from(ucs: Cam16Ucs) -> Self {
+        ucs.to_rgb()
+    }
+}
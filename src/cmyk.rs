@@ -0,0 +1,172 @@
+use crate::RGB;
+
+/// A device CMYK color with components as ink percentages `[0, 100]`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CMYK {
+    pub c: f64,
+    pub m: f64,
+    pub y: f64,
+    pub k: f64,
+}
+
+impl CMYK {
+    /// Creates a new CMYK color.
+    pub fn new(c: f64, m: f64, y: f64, k: f64) -> Self {
+        CMYK { c, m, y, k }
+    }
+
+    /// Total ink coverage in percent, as checked against press limits.
+    pub fn total_ink(self) -> f64 {
+        self.c + self.m + self.y + self.k
+    }
+}
+
+/// A strategy for separating RGB colors into CMYK inks.
+///
+/// [`NaiveCmyk`] is the built-in formula; a color-managed implementation
+/// backed by an ICC output profile can be dropped in wherever a
+/// `CmykConverter` is accepted.
+pub trait CmykConverter {
+    /// Separates an sRGB color into CMYK.
+    fn to_cmyk(&self, rgb: RGB) -> CMYK;
+
+    /// Reconstructs the sRGB color a CMYK separation represents.
+    fn to_rgb(&self, cmyk: CMYK) -> RGB;
+}
+
+/// Device-dependent CMYK using the textbook complement formula with
+/// configurable black generation and under-color removal.
+///
+/// [`to_rgb`](CmykConverter::to_rgb) is the exact inverse of
+/// [`to_cmyk`](CmykConverter::to_cmyk) for the same settings, so separations
+/// round-trip. With full under-color removal it is the ink-only
+/// `(1 - c)(1 - k)` formula; with less, only the removed part of the black
+/// ink is counted, as the rest duplicates gray still in the colored inks.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NaiveCmyk {
+    /// Fraction `[0, 1]` of the gray component printed with black ink.
+    pub black_generation: f64,
+    /// Fraction `[0, 1]` of the black ink removed from the colored inks.
+    pub under_color_removal: f64,
+}
+
+impl Default for NaiveCmyk {
+    /// Full black generation and under-color removal (maximum GCR).
+    fn default() -> Self {
+        NaiveCmyk {
+            black_generation: 1.0,
+            under_color_removal: 1.0,
+        }
+    }
+}
+
+impl NaiveCmyk {
+    /// Creates a converter with the given black generation and under-color
+    /// removal fractions, each clamped to `[0, 1]`.
+    pub fn new(black_generation: f64, under_color_removal: f64) -> Self {
+        NaiveCmyk {
+            black_generation: black_generation.clamp(0.0, 1.0),
+            under_color_removal: under_color_removal.clamp(0.0, 1.0),
+        }
+    }
+}
+
+impl CmykConverter for NaiveCmyk {
+    fn to_cmyk(&self, rgb: RGB) -> CMYK {
+        let c = 1.0 - rgb.r as f64 / 255.0;
+        let m = 1.0 - rgb.g as f64 / 255.0;
+        let y = 1.0 - rgb.b as f64 / 255.0;
+
+        let k = self.black_generation * c.min(m.min(y));
+        let removed = self.under_color_removal * k;
+        let remove = |x: f64| {
+            if removed >= 1.0 { 0.0 } else { (x - removed) / (1.0 - removed) }
+        };
+
+        CMYK {
+            c: remove(c) * 100.0,
+            m: remove(m) * 100.0,
+            y: remove(y) * 100.0,
+            k: k * 100.0,
+        }
+    }
+
+    fn to_rgb(&self, cmyk: CMYK) -> RGB {
+        let removed = self.under_color_removal * (cmyk.k / 100.0).clamp(0.0, 1.0);
+        let channel = |x: f64| {
+            ((1.0 - (x / 100.0).clamp(0.0, 1.0)) * (1.0 - removed) * 255.0).round() as u8
+        };
+
+        RGB {
+            r: channel(cmyk.c),
+            g: channel(cmyk.m),
+            b: channel(cmyk.y),
+        }
+    }
+}
+
+impl RGB {
+    /// Converts to device CMYK with the default [`NaiveCmyk`] separation.
+    pub fn to_cmyk(self) -> CMYK {
+        NaiveCmyk::default().to_cmyk(self)
+    }
+
+    /// Converts to CMYK with the given separation strategy.
+    pub fn to_cmyk_with(self, converter: &impl CmykConverter) -> CMYK {
+        converter.to_cmyk(self)
+    }
+}
+
+impl From<RGB> for CMYK {
+    fn from(rgb: RGB) -> Self {
+        rgb.to_cmyk()
+    }
+}
+
+impl From<CMYK> for RGB {
+    fn from(cmyk: CMYK) -> Self {
+        NaiveCmyk::default().to_rgb(cmyk)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> impl Iterator<Item = RGB> {
+        let levels = [0u8, 1, 37, 64, 128, 200, 254, 255];
+        levels.into_iter().flat_map(move |r| {
+            levels.into_iter().flat_map(move |g| levels.into_iter().map(move |b| RGB::new(r, g, b)))
+        })
+    }
+
+    #[test]
+    fn separations_round_trip() {
+        for ucr in [0.0, 0.5, 1.0] {
+            for black_generation in [0.0, 0.5, 1.0] {
+                let converter = NaiveCmyk::new(black_generation, ucr);
+                for rgb in grid() {
+                    let cmyk = converter.to_cmyk(rgb);
+                    assert_eq!(converter.to_rgb(cmyk), rgb, "{:?} via {:?} with {:?}", rgb, cmyk, converter);
+
+                    let again = converter.to_cmyk(converter.to_rgb(cmyk));
+                    for (a, b) in [(again.c, cmyk.c), (again.m, cmyk.m), (again.y, cmyk.y), (again.k, cmyk.k)] {
+                        assert!((a - b).abs() < 1e-9, "{:?} != {:?} with {:?}", again, cmyk, converter);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn default_is_textbook_formula() {
+        let cmyk = RGB::new(128, 64, 32).to_cmyk();
+        let k = 1.0 - 128.0 / 255.0;
+        let expected = [0.0, (1.0 - 64.0 / 255.0 - k) / (1.0 - k), (1.0 - 32.0 / 255.0 - k) / (1.0 - k), k];
+        for (actual, expected) in [cmyk.c, cmyk.m, cmyk.y, cmyk.k].into_iter().zip(expected) {
+            assert!((actual - expected * 100.0).abs() < 1e-9);
+        }
+        assert_eq!(RGB::from(CMYK::new(0.0, 0.0, 0.0, 100.0)), RGB::new(0, 0, 0));
+        assert_eq!(RGB::from(CMYK::new(100.0, 0.0, 0.0, 0.0)), RGB::new(0, 255, 255));
+    }
+}
@@ -0,0 +1,319 @@
+//! Perceptual uniformity analysis of colormaps.
+
+use std::fmt::{self, Write};
+
+use super::export::escape_json;
+use super::{Colormap, ColormapKind};
+use crate::{Cam16Ucs, Lab, WhitePoint, XYZ};
+
+/// A defect found while analyzing a colormap.
+#[derive(Debug, Clone, PartialEq)]
+pub enum UniformityIssue {
+    /// Lightness changes direction at `t` more often than the colormap kind
+    /// allows.
+    LightnessReversal { t: f64 },
+    /// Between `start` and `end` the perceptual step deviates from the mean
+    /// step by up to `ratio` (1.0 meaning twice the mean, -0.5 half of it),
+    /// producing visible bands or flat regions.
+    Banding { start: f64, end: f64, ratio: f64 },
+    /// A cyclic colormap jumps by `delta_e` where its ends meet, more than a
+    /// regular step allows.
+    Seam { delta_e: f64 },
+}
+
+/// The perceptual profile of a sampled colormap.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UniformityReport {
+    pub name: String,
+    pub kind: ColormapKind,
+    /// Positions in `[0, 1]` of the samples.
+    pub t: Vec<f64>,
+    /// CIELAB L* of each sample.
+    pub lightness: Vec<f64>,
+    /// CAM16-UCS J′ of each sample.
+    pub lightness_cam16: Vec<f64>,
+    /// CAM16-UCS ΔE between each pair of adjacent samples.
+    pub delta_e: Vec<f64>,
+    /// Sum of `delta_e`: the perceptual length of the colormap.
+    pub total_length: f64,
+    /// Coefficient of variation of `delta_e`; zero for a perfectly uniform map.
+    pub step_variation: f64,
+    /// CAM16-UCS ΔE between the two ends, as returned by
+    /// [`Colormap::seam`].
+    pub seam: f64,
+    pub issues: Vec<UniformityIssue>,
+}
+
+impl Colormap {
+    /// Returns the CAM16-UCS ΔE between the colors at `t = 1` and `t = 0`,
+    /// the jump seen where a cyclic colormap wraps around.
+    pub fn seam(&self) -> f64 {
+        let end = Cam16Ucs::from_xyz(self.sample_linear(1.0).to_xyz());
+        let start = Cam16Ucs::from_xyz(self.sample_linear(0.0).to_xyz());
+        end.distance(start)
+    }
+
+    /// Samples the colormap `samples` times and measures its lightness
+    /// profile and local perceptual steps.
+    ///
+    /// Samples are taken before 8-bit quantization, so the steps reflect the
+    /// colormap rather than rounding noise. Where the distance covered over
+    /// about 2% of the map differs from the mean rate by more than
+    /// `tolerance` (a fraction of the mean), it is reported as banding.
+    /// Lightness may turn around once in a diverging map; cyclic maps are
+    /// not checked for lightness reversals, but their [`seam`](Self::seam)
+    /// must be no larger than a regular step within the same tolerance.
+    pub fn analyze(&self, samples: usize, tolerance: f64) -> UniformityReport {
+        let samples = samples.max(3);
+        let t: Vec<f64> = (0..samples).map(|i| i as f64 / (samples - 1) as f64).collect();
+        let xyz: Vec<XYZ> = t.iter().map(|&t| self.sample_linear(t).to_xyz()).collect();
+        let lightness: Vec<f64> = xyz.iter().map(|&c| Lab::from_xyz(c, WhitePoint::D65).l).collect();
+        let ucs: Vec<Cam16Ucs> = xyz.iter().map(|&c| Cam16Ucs::from_xyz(c)).collect();
+        let lightness_cam16: Vec<f64> = ucs.iter().map(|c| c.j).collect();
+        let delta_e: Vec<f64> = ucs.windows(2).map(|w| w[0].distance(w[1])).collect();
+
+        let total_length: f64 = delta_e.iter().sum();
+        let mean = total_length / delta_e.len() as f64;
+        let variance = delta_e.iter().map(|d| (d - mean).powi(2)).sum::<f64>() / delta_e.len() as f64;
+        let step_variation = if mean > 0.0 { variance.sqrt() / mean } else { 0.0 };
+
+        let mut issues = Vec::new();
+
+        // Lightness must move back by more than this (in J′) from its last
+        // extreme to count as a reversal, so quantization noise is ignored
+        const HYSTERESIS: f64 = 0.5;
+        let allowed_turns = match self.kind {
+            ColormapKind::Sequential | ColormapKind::Rainbow => Some(0),
+            ColormapKind::Diverging => Some(1),
+            ColormapKind::Cyclic => None,
+        };
+        if let Some(allowed) = allowed_turns {
+            let mut direction = 0.0;
+            let mut extreme = 0;
+            let mut turns = 0;
+            for (i, &l) in lightness_cam16.iter().enumerate() {
+                let change = l - lightness_cam16[extreme];
+                if change * direction > 0.0 {
+                    extreme = i;
+                } else if change.abs() > HYSTERESIS {
+                    if direction != 0.0 {
+                        turns += 1;
+                        if turns > allowed {
+                            issues.push(UniformityIssue::LightnessReversal { t: t[extreme] });
+                        }
+                    }
+                    direction = change.signum();
+                    extreme = i;
+                }
+            }
+        }
+
+        // Judge banding on the distance covered over about 2% of the map,
+        // measured straight across rather than summed step by step, so the
+        // zig-zag of 8-bit rounding neither counts as a band nor adds length
+        let half = samples / 100;
+        let speed = |i: usize| {
+            let (a, b) = (i.saturating_sub(half), (i + half + 1).min(ucs.len() - 1));
+            ucs[a].distance(ucs[b]) / (b - a) as f64
+        };
+        let smoothed: Vec<f64> = (0..delta_e.len()).map(speed).collect();
+        let mean_speed = smoothed.iter().sum::<f64>() / smoothed.len() as f64;
+
+        if mean_speed > 0.0 {
+            let mut run: Option<(usize, f64)> = None;
+            for (i, d) in smoothed.iter().enumerate() {
+                let ratio = d / mean_speed - 1.0;
+                let flagged = ratio.abs() > tolerance;
+                // A run ends when the step is back in tolerance or flips
+                // between too large and too small
+                if let Some((start, worst)) = run {
+                    if !flagged || ratio.signum() != worst.signum() {
+                        issues.push(UniformityIssue::Banding { start: t[start], end: t[i], ratio: worst });
+                        run = None;
+                    }
+                }
+                if flagged {
+                    run = match run {
+                        Some((start, worst)) if worst.abs() >= ratio.abs() => Some((start, worst)),
+                        Some((start, _)) => Some((start, ratio)),
+                        None => Some((i, ratio)),
+                    };
+                }
+            }
+            if let Some((start, worst)) = run {
+                issues.push(UniformityIssue::Banding { start: t[start], end: 1.0, ratio: worst });
+            }
+        }
+
+        let seam = self.seam();
+        if self.kind == ColormapKind::Cyclic && seam > mean * (1.0 + tolerance) {
+            issues.push(UniformityIssue::Seam { delta_e: seam });
+        }
+
+        UniformityReport {
+            name: self.name.clone(),
+            kind: self.kind,
+            t,
+            lightness,
+            lightness_cam16,
+            delta_e,
+            total_length,
+            step_variation,
+            seam,
+            issues,
+        }
+    }
+}
+
+fn json_array(values: &[f64]) -> String {
+    let items: Vec<String> = values.iter().map(|v| format!("{:.4}", v)).collect();
+    format!("[{}]", items.join(", "))
+}
+
+impl UniformityReport {
+    /// Returns true if no issues were found.
+    pub fn passed(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Formats a human-readable summary with a lightness profile at `rows`
+    /// evenly spaced positions.
+    pub fn to_text(&self, rows: usize) -> String {
+        let mut out = String::new();
+        self.write_text(&mut out, rows).expect("writing to a String cannot fail");
+        out
+    }
+
+    /// Writes the summary produced by [`to_text`](Self::to_text) to `out`.
+    pub fn write_text(&self, out: &mut impl Write, rows: usize) -> fmt::Result {
+        writeln!(out, "Colormap:             {} ({})", self.name, self.kind)?;
+        writeln!(out, "Samples:              {}", self.t.len())?;
+        writeln!(out, "Perceptual length:    {:.2} ΔE (CAM16-UCS)", self.total_length)?;
+        writeln!(out, "Step variation:       {:.1}%", self.step_variation * 100.0)?;
+        writeln!(out, "Seam:                 {:.2} ΔE", self.seam)?;
+        writeln!(out, "Lightness:            L* {:.1} → {:.1}",
+            self.lightness[0], self.lightness[self.lightness.len() - 1])?;
+
+        writeln!(out, "\n     t      L*      J'    local ΔE")?;
+        let n = self.t.len();
+        let rows = rows.max(2);
+        for row in 0..rows {
+            let i = row * (n - 1) / (rows - 1);
+            writeln!(out, "  {:.3}  {:>6.2}  {:>6.2}  {:>8.3}",
+                self.t[i], self.lightness[i], self.lightness_cam16[i], self.delta_e[i.min(n - 2)])?;
+        }
+
+        if self.issues.is_empty() {
+            return writeln!(out, "\nNo issues found");
+        }
+        writeln!(out, "\nIssues:")?;
+        for issue in &self.issues {
+            match issue {
+                UniformityIssue::LightnessReversal { t } => {
+                    writeln!(out, "  lightness reverses direction at t = {:.3}", t)?
+                },
+                UniformityIssue::Banding { start, end, ratio } => {
+                    writeln!(out, "  {} steps between t = {:.3} and {:.3} ({:+.0}% of the mean)",
+                        if *ratio > 0.0 { "large" } else { "small" }, start, end, ratio * 100.0)?
+                },
+                UniformityIssue::Seam { delta_e } => {
+                    writeln!(out, "  the ends differ by {:.2} ΔE where the colormap wraps around", delta_e)?
+                },
+            }
+        }
+        Ok(())
+    }
+
+    /// Formats the full report as JSON.
+    pub fn to_json(&self) -> String {
+        let issues: Vec<String> = self.issues.iter()
+            .map(|issue| match issue {
+                UniformityIssue::LightnessReversal { t } => format!(
+                    "{{\"type\": \"lightness_reversal\", \"t\": {:.4}}}", t),
+                UniformityIssue::Banding { start, end, ratio } => format!(
+                    "{{\"type\": \"banding\", \"start\": {:.4}, \"end\": {:.4}, \"ratio\": {:.4}}}",
+                    start, end, ratio),
+                UniformityIssue::Seam { delta_e } => format!(
+                    "{{\"type\": \"seam\", \"delta_e\": {:.4}}}", delta_e),
+            })
+            .collect();
+
+        let fields = [
+            ("name", format!("\"{}\"", escape_json(&self.name))),
+            ("kind", format!("\"{}\"", self.kind)),
+            ("samples", self.t.len().to_string()),
+            ("total_length", format!("{:.4}", self.total_length)),
+            ("step_variation", format!("{:.4}", self.step_variation)),
+            ("seam", format!("{:.4}", self.seam)),
+            ("passed", self.passed().to_string()),
+            ("t", json_array(&self.t)),
+            ("lightness_cielab", json_array(&self.lightness)),
+            ("lightness_cam16ucs", json_array(&self.lightness_cam16)),
+            ("delta_e", json_array(&self.delta_e)),
+            ("issues", format!("[{}]", issues.join(", "))),
+        ];
+        let body: Vec<String> = fields.iter()
+            .map(|(key, value)| format!("  \"{}\": {}", key, value))
+            .collect();
+        format!("{{\n{}\n}}\n", body.join(",\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{InterpolationSpace, RGB};
+
+    fn grays(levels: &[u8]) -> Vec<RGB> {
+        levels.iter().map(|&v| RGB::new(v, v, v)).collect()
+    }
+
+    #[test]
+    fn viridis_passes() {
+        let report = Colormap::builtin("viridis").unwrap().analyze(256, 0.5);
+        assert!(report.passed(), "{:?}", report.issues);
+        assert_eq!(report.kind, ColormapKind::Sequential);
+        assert!(report.lightness_cam16[255] > report.lightness_cam16[0] + 60.0);
+    }
+
+    #[test]
+    fn flags_lightness_reversal() {
+        let colormap = Colormap::new("bump", ColormapKind::Sequential, grays(&[0, 255, 100]));
+        let report = colormap.analyze(256, 0.5);
+        assert!(report.issues.iter().any(|issue| matches!(
+            issue, UniformityIssue::LightnessReversal { t } if (t - 0.5).abs() < 0.01)));
+
+        // The same profile is allowed once in a diverging map
+        let diverging = Colormap { kind: ColormapKind::Diverging, ..colormap };
+        let report = diverging.analyze(256, 0.5);
+        assert!(!report.issues.iter().any(|issue| matches!(issue, UniformityIssue::LightnessReversal { .. })));
+    }
+
+    #[test]
+    fn flags_banding() {
+        let ramp = Colormap::new("ramp", ColormapKind::Sequential, grays(&[0, 255]))
+            .with_interpolation(InterpolationSpace::Cam16Ucs);
+        assert!(ramp.analyze(256, 0.5).passed());
+
+        let jump = Colormap::new("jump", ColormapKind::Sequential,
+            grays(&[0, 20, 40, 60, 200, 210, 220, 230, 240, 250]))
+            .with_interpolation(InterpolationSpace::Cam16Ucs);
+        let report = jump.analyze(256, 0.5);
+        assert!(report.issues.iter().any(|issue| matches!(
+            issue, UniformityIssue::Banding { start, end, ratio } if *start < 0.4 && *end > 0.3 && *ratio > 0.5)),
+            "{:?}", report.issues);
+    }
+
+    #[test]
+    fn flags_seam_of_open_cyclic_map() {
+        let open = Colormap::new("open", ColormapKind::Cyclic,
+            vec![RGB::new(255, 0, 0), RGB::new(0, 255, 0), RGB::new(0, 0, 255)]);
+        let report = open.analyze(256, 0.5);
+        assert!(report.issues.iter().any(|issue| matches!(issue, UniformityIssue::Seam { .. })));
+
+        let closed = Colormap::hue_circle("circle", 0.7, InterpolationSpace::Oklab, 64).unwrap();
+        let report = closed.analyze(256, 0.5);
+        assert!(report.seam < 1e-9);
+        assert!(!report.issues.iter().any(|issue| matches!(issue, UniformityIssue::Seam { .. })));
+    }
+}
@@ -0,0 +1,398 @@
+//! Control points of the built-in colormaps.
+//!
+//! The matplotlib maps and turbo are stored as their full 256-entry published
+//! lookup tables. The Crameri maps (batlow, roma, vik) are still only ten
+//! evenly spaced samples of theirs (Crameri, *Scientific colour maps*,
+//! doi:10.5281/zenodo.1243862), so everything between those samples is
+//! interpolated, including the neutral centre of roma and vik, which falls
+//! between the fifth and sixth sample. They should be replaced by the
+//! published 256-entry tables. All are ordered from the low to the high end of
+//! the data range.
+
+use super::ColormapKind;
+use crate::RGB;
+
+pub(crate) struct Builtin {
+    pub name: &'static str,
+    pub kind: ColormapKind,
+    pub colors: &'static [RGB],
+}
+
+const fn hex(v: u32) -> RGB {
+    RGB::new((v >> 16) as u8, (v >> 8) as u8, v as u8)
+}
+
+pub(crate) const BUILTINS: &[Builtin] = &[
+    Builtin {
+        name: "viridis",
+        kind: ColormapKind::Sequential,
+        colors: &[
+            hex(0x440154), hex(0x440256), hex(0x450457), hex(0x450559), hex(0x46075A),
+            hex(0x46085C), hex(0x460A5D), hex(0x460B5E), hex(0x470D60), hex(0x470E61),
+            hex(0x471063), hex(0x471164), hex(0x471365), hex(0x481467), hex(0x481668),
+            hex(0x481769), hex(0x48186A), hex(0x481A6C), hex(0x481B6D), hex(0x481C6E),
+            hex(0x481D6F), hex(0x481F70), hex(0x482071), hex(0x482173), hex(0x482374),
+            hex(0x482475), hex(0x482576), hex(0x482677), hex(0x482878), hex(0x482979),
+            hex(0x472A7A), hex(0x472C7A), hex(0x472D7B), hex(0x472E7C), hex(0x472F7D),
+            hex(0x46307E), hex(0x46327E), hex(0x46337F), hex(0x463480), hex(0x453581),
+            hex(0x453781), hex(0x453882), hex(0x443983), hex(0x443A83), hex(0x443B84),
+            hex(0x433D84), hex(0x433E85), hex(0x423F85), hex(0x424086), hex(0x424186),
+            hex(0x414287), hex(0x414487), hex(0x404588), hex(0x404688), hex(0x3F4788),
+            hex(0x3F4889), hex(0x3E4989), hex(0x3E4A89), hex(0x3E4C8A), hex(0x3D4D8A),
+            hex(0x3D4E8A), hex(0x3C4F8A), hex(0x3C508B), hex(0x3B518B), hex(0x3B528B),
+            hex(0x3A538B), hex(0x3A548C), hex(0x39558C), hex(0x39568C), hex(0x38588C),
+            hex(0x38598C), hex(0x375A8C), hex(0x375B8D), hex(0x365C8D), hex(0x365D8D),
+            hex(0x355E8D), hex(0x355F8D), hex(0x34608D), hex(0x34618D), hex(0x33628D),
+            hex(0x33638D), hex(0x32648E), hex(0x32658E), hex(0x31668E), hex(0x31678E),
+            hex(0x31688E), hex(0x30698E), hex(0x306A8E), hex(0x2F6B8E), hex(0x2F6C8E),
+            hex(0x2E6D8E), hex(0x2E6E8E), hex(0x2E6F8E), hex(0x2D708E), hex(0x2D718E),
+            hex(0x2C718E), hex(0x2C728E), hex(0x2C738E), hex(0x2B748E), hex(0x2B758E),
+            hex(0x2A768E), hex(0x2A778E), hex(0x2A788E), hex(0x29798E), hex(0x297A8E),
+            hex(0x297B8E), hex(0x287C8E), hex(0x287D8E), hex(0x277E8E), hex(0x277F8E),
+            hex(0x27808E), hex(0x26818E), hex(0x26828E), hex(0x26828E), hex(0x25838E),
+            hex(0x25848E), hex(0x25858E), hex(0x24868E), hex(0x24878E), hex(0x23888E),
+            hex(0x23898E), hex(0x238A8D), hex(0x228B8D), hex(0x228C8D), hex(0x228D8D),
+            hex(0x218E8D), hex(0x218F8D), hex(0x21908D), hex(0x21918C), hex(0x20928C),
+            hex(0x20928C), hex(0x20938C), hex(0x1F948C), hex(0x1F958B), hex(0x1F968B),
+            hex(0x1F978B), hex(0x1F988B), hex(0x1F998A), hex(0x1F9A8A), hex(0x1E9B8A),
+            hex(0x1E9C89), hex(0x1E9D89), hex(0x1F9E89), hex(0x1F9F88), hex(0x1FA088),
+            hex(0x1FA188), hex(0x1FA187), hex(0x1FA287), hex(0x20A386), hex(0x20A486),
+            hex(0x21A585), hex(0x21A685), hex(0x22A785), hex(0x22A884), hex(0x23A983),
+            hex(0x24AA83), hex(0x25AB82), hex(0x25AC82), hex(0x26AD81), hex(0x27AD81),
+            hex(0x28AE80), hex(0x29AF7F), hex(0x2AB07F), hex(0x2CB17E), hex(0x2DB27D),
+            hex(0x2EB37C), hex(0x2FB47C), hex(0x31B57B), hex(0x32B67A), hex(0x34B679),
+            hex(0x35B779), hex(0x37B878), hex(0x38B977), hex(0x3ABA76), hex(0x3BBB75),
+            hex(0x3DBC74), hex(0x3FBC73), hex(0x40BD72), hex(0x42BE71), hex(0x44BF70),
+            hex(0x46C06F), hex(0x48C16E), hex(0x4AC16D), hex(0x4CC26C), hex(0x4EC36B),
+            hex(0x50C46A), hex(0x52C569), hex(0x54C568), hex(0x56C667), hex(0x58C765),
+            hex(0x5AC864), hex(0x5CC863), hex(0x5EC962), hex(0x60CA60), hex(0x63CB5F),
+            hex(0x65CB5E), hex(0x67CC5C), hex(0x69CD5B), hex(0x6CCD5A), hex(0x6ECE58),
+            hex(0x70CF57), hex(0x73D056), hex(0x75D054), hex(0x77D153), hex(0x7AD151),
+            hex(0x7CD250), hex(0x7FD34E), hex(0x81D34D), hex(0x84D44B), hex(0x86D549),
+            hex(0x89D548), hex(0x8BD646), hex(0x8ED645), hex(0x90D743), hex(0x93D741),
+            hex(0x95D840), hex(0x98D83E), hex(0x9BD93C), hex(0x9DD93B), hex(0xA0DA39),
+            hex(0xA2DA37), hex(0xA5DB36), hex(0xA8DB34), hex(0xAADC32), hex(0xADDC30),
+            hex(0xB0DD2F), hex(0xB2DD2D), hex(0xB5DE2B), hex(0xB8DE29), hex(0xBADE28),
+            hex(0xBDDF26), hex(0xC0DF25), hex(0xC2DF23), hex(0xC5E021), hex(0xC8E020),
+            hex(0xCAE11F), hex(0xCDE11D), hex(0xD0E11C), hex(0xD2E21B), hex(0xD5E21A),
+            hex(0xD8E219), hex(0xDAE319), hex(0xDDE318), hex(0xDFE318), hex(0xE2E418),
+            hex(0xE5E419), hex(0xE7E419), hex(0xEAE51A), hex(0xECE51B), hex(0xEFE51C),
+            hex(0xF1E51D), hex(0xF4E61E), hex(0xF6E620), hex(0xF8E621), hex(0xFBE723),
+            hex(0xFDE725),
+        ],
+    },
+    Builtin {
+        name: "magma",
+        kind: ColormapKind::Sequential,
+        colors: &[
+            hex(0x000004), hex(0x010005), hex(0x010106), hex(0x010108), hex(0x020109),
+            hex(0x02020B), hex(0x02020D), hex(0x03030F), hex(0x030312), hex(0x040414),
+            hex(0x050416), hex(0x060518), hex(0x06051A), hex(0x07061C), hex(0x08071E),
+            hex(0x090720), hex(0x0A0822), hex(0x0B0924), hex(0x0C0926), hex(0x0D0A29),
+            hex(0x0E0B2B), hex(0x100B2D), hex(0x110C2F), hex(0x120D31), hex(0x130D34),
+            hex(0x140E36), hex(0x150E38), hex(0x160F3B), hex(0x180F3D), hex(0x19103F),
+            hex(0x1A1042), hex(0x1C1044), hex(0x1D1147), hex(0x1E1149), hex(0x20114B),
+            hex(0x21114E), hex(0x221150), hex(0x241253), hex(0x251255), hex(0x271258),
+            hex(0x29115A), hex(0x2A115C), hex(0x2C115F), hex(0x2D1161), hex(0x2F1163),
+            hex(0x311165), hex(0x331067), hex(0x341069), hex(0x36106B), hex(0x38106C),
+            hex(0x390F6E), hex(0x3B0F70), hex(0x3D0F71), hex(0x3F0F72), hex(0x400F74),
+            hex(0x420F75), hex(0x440F76), hex(0x451077), hex(0x471078), hex(0x491078),
+            hex(0x4A1079), hex(0x4C117A), hex(0x4E117B), hex(0x4F127B), hex(0x51127C),
+            hex(0x52137C), hex(0x54137D), hex(0x56147D), hex(0x57157E), hex(0x59157E),
+            hex(0x5A167E), hex(0x5C167F), hex(0x5D177F), hex(0x5F187F), hex(0x601880),
+            hex(0x621980), hex(0x641A80), hex(0x651A80), hex(0x671B80), hex(0x681C81),
+            hex(0x6A1C81), hex(0x6B1D81), hex(0x6D1D81), hex(0x6E1E81), hex(0x701F81),
+            hex(0x721F81), hex(0x732081), hex(0x752181), hex(0x762181), hex(0x782281),
+            hex(0x792282), hex(0x7B2382), hex(0x7C2382), hex(0x7E2482), hex(0x802582),
+            hex(0x812581), hex(0x832681), hex(0x842681), hex(0x862781), hex(0x882781),
+            hex(0x892881), hex(0x8B2981), hex(0x8C2981), hex(0x8E2A81), hex(0x902A81),
+            hex(0x912B81), hex(0x932B80), hex(0x942C80), hex(0x962C80), hex(0x982D80),
+            hex(0x992D80), hex(0x9B2E7F), hex(0x9C2E7F), hex(0x9E2F7F), hex(0xA02F7F),
+            hex(0xA1307E), hex(0xA3307E), hex(0xA5317E), hex(0xA6317D), hex(0xA8327D),
+            hex(0xAA337D), hex(0xAB337C), hex(0xAD347C), hex(0xAE347B), hex(0xB0357B),
+            hex(0xB2357B), hex(0xB3367A), hex(0xB5367A), hex(0xB73779), hex(0xB83779),
+            hex(0xBA3878), hex(0xBC3978), hex(0xBD3977), hex(0xBF3A77), hex(0xC03A76),
+            hex(0xC23B75), hex(0xC43C75), hex(0xC53C74), hex(0xC73D73), hex(0xC83E73),
+            hex(0xCA3E72), hex(0xCC3F71), hex(0xCD4071), hex(0xCF4070), hex(0xD0416F),
+            hex(0xD2426F), hex(0xD3436E), hex(0xD5446D), hex(0xD6456C), hex(0xD8456C),
+            hex(0xD9466B), hex(0xDB476A), hex(0xDC4869), hex(0xDE4968), hex(0xDF4A68),
+            hex(0xE04C67), hex(0xE24D66), hex(0xE34E65), hex(0xE44F64), hex(0xE55064),
+            hex(0xE75263), hex(0xE85362), hex(0xE95462), hex(0xEA5661), hex(0xEB5760),
+            hex(0xEC5860), hex(0xED5A5F), hex(0xEE5B5E), hex(0xEF5D5E), hex(0xF05F5E),
+            hex(0xF1605D), hex(0xF2625D), hex(0xF2645C), hex(0xF3655C), hex(0xF4675C),
+            hex(0xF4695C), hex(0xF56B5C), hex(0xF66C5C), hex(0xF66E5C), hex(0xF7705C),
+            hex(0xF7725C), hex(0xF8745C), hex(0xF8765C), hex(0xF9785D), hex(0xF9795D),
+            hex(0xF97B5D), hex(0xFA7D5E), hex(0xFA7F5E), hex(0xFA815F), hex(0xFB835F),
+            hex(0xFB8560), hex(0xFB8761), hex(0xFC8961), hex(0xFC8A62), hex(0xFC8C63),
+            hex(0xFC8E64), hex(0xFC9065), hex(0xFD9266), hex(0xFD9467), hex(0xFD9668),
+            hex(0xFD9869), hex(0xFD9A6A), hex(0xFD9B6B), hex(0xFE9D6C), hex(0xFE9F6D),
+            hex(0xFEA16E), hex(0xFEA36F), hex(0xFEA571), hex(0xFEA772), hex(0xFEA973),
+            hex(0xFEAA74), hex(0xFEAC76), hex(0xFEAE77), hex(0xFEB078), hex(0xFEB27A),
+            hex(0xFEB47B), hex(0xFEB67C), hex(0xFEB77E), hex(0xFEB97F), hex(0xFEBB81),
+            hex(0xFEBD82), hex(0xFEBF84), hex(0xFEC185), hex(0xFEC287), hex(0xFEC488),
+            hex(0xFEC68A), hex(0xFEC88C), hex(0xFECA8D), hex(0xFECC8F), hex(0xFECD90),
+            hex(0xFECF92), hex(0xFED194), hex(0xFED395), hex(0xFED597), hex(0xFED799),
+            hex(0xFED89A), hex(0xFDDA9C), hex(0xFDDC9E), hex(0xFDDEA0), hex(0xFDE0A1),
+            hex(0xFDE2A3), hex(0xFDE3A5), hex(0xFDE5A7), hex(0xFDE7A9), hex(0xFDE9AA),
+            hex(0xFDEBAC), hex(0xFCECAE), hex(0xFCEEB0), hex(0xFCF0B2), hex(0xFCF2B4),
+            hex(0xFCF4B6), hex(0xFCF6B8), hex(0xFCF7B9), hex(0xFCF9BB), hex(0xFCFBBD),
+            hex(0xFCFDBF),
+        ],
+    },
+    Builtin {
+        name: "inferno",
+        kind: ColormapKind::Sequential,
+        colors: &[
+            hex(0x000004), hex(0x010005), hex(0x010106), hex(0x010108), hex(0x02010A),
+            hex(0x02020C), hex(0x02020E), hex(0x030210), hex(0x040312), hex(0x040314),
+            hex(0x050417), hex(0x060419), hex(0x07051B), hex(0x08051D), hex(0x09061F),
+            hex(0x0A0722), hex(0x0B0724), hex(0x0C0826), hex(0x0D0829), hex(0x0E092B),
+            hex(0x10092D), hex(0x110A30), hex(0x120A32), hex(0x140B34), hex(0x150B37),
+            hex(0x160B39), hex(0x180C3C), hex(0x190C3E), hex(0x1B0C41), hex(0x1C0C43),
+            hex(0x1E0C45), hex(0x1F0C48), hex(0x210C4A), hex(0x230C4C), hex(0x240C4F),
+            hex(0x260C51), hex(0x280B53), hex(0x290B55), hex(0x2B0B57), hex(0x2D0B59),
+            hex(0x2F0A5B), hex(0x310A5C), hex(0x320A5E), hex(0x340A5F), hex(0x360961),
+            hex(0x380962), hex(0x390963), hex(0x3B0964), hex(0x3D0965), hex(0x3E0966),
+            hex(0x400A67), hex(0x420A68), hex(0x440A68), hex(0x450A69), hex(0x470B6A),
+            hex(0x490B6A), hex(0x4A0C6B), hex(0x4C0C6B), hex(0x4D0D6C), hex(0x4F0D6C),
+            hex(0x510E6C), hex(0x520E6D), hex(0x540F6D), hex(0x550F6D), hex(0x57106E),
+            hex(0x59106E), hex(0x5A116E), hex(0x5C126E), hex(0x5D126E), hex(0x5F136E),
+            hex(0x61136E), hex(0x62146E), hex(0x64156E), hex(0x65156E), hex(0x67166E),
+            hex(0x69166E), hex(0x6A176E), hex(0x6C186E), hex(0x6D186E), hex(0x6F196E),
+            hex(0x71196E), hex(0x721A6E), hex(0x741A6E), hex(0x751B6E), hex(0x771C6D),
+            hex(0x781C6D), hex(0x7A1D6D), hex(0x7C1D6D), hex(0x7D1E6D), hex(0x7F1E6C),
+            hex(0x801F6C), hex(0x82206C), hex(0x84206B), hex(0x85216B), hex(0x87216B),
+            hex(0x88226A), hex(0x8A226A), hex(0x8C2369), hex(0x8D2369), hex(0x8F2469),
+            hex(0x902568), hex(0x922568), hex(0x932667), hex(0x952667), hex(0x972766),
+            hex(0x982766), hex(0x9A2865), hex(0x9B2964), hex(0x9D2964), hex(0x9F2A63),
+            hex(0xA02A63), hex(0xA22B62), hex(0xA32C61), hex(0xA52C60), hex(0xA62D60),
+            hex(0xA82E5F), hex(0xA92E5E), hex(0xAB2F5E), hex(0xAD305D), hex(0xAE305C),
+            hex(0xB0315B), hex(0xB1325A), hex(0xB3325A), hex(0xB43359), hex(0xB63458),
+            hex(0xB73557), hex(0xB93556), hex(0xBA3655), hex(0xBC3754), hex(0xBD3853),
+            hex(0xBF3952), hex(0xC03A51), hex(0xC13A50), hex(0xC33B4F), hex(0xC43C4E),
+            hex(0xC63D4D), hex(0xC73E4C), hex(0xC83F4B), hex(0xCA404A), hex(0xCB4149),
+            hex(0xCC4248), hex(0xCE4347), hex(0xCF4446), hex(0xD04545), hex(0xD24644),
+            hex(0xD34743), hex(0xD44842), hex(0xD54A41), hex(0xD74B3F), hex(0xD84C3E),
+            hex(0xD94D3D), hex(0xDA4E3C), hex(0xDB503B), hex(0xDD513A), hex(0xDE5238),
+            hex(0xDF5337), hex(0xE05536), hex(0xE15635), hex(0xE25734), hex(0xE35933),
+            hex(0xE45A31), hex(0xE55C30), hex(0xE65D2F), hex(0xE75E2E), hex(0xE8602D),
+            hex(0xE9612B), hex(0xEA632A), hex(0xEB6429), hex(0xEB6628), hex(0xEC6726),
+            hex(0xED6925), hex(0xEE6A24), hex(0xEF6C23), hex(0xEF6E21), hex(0xF06F20),
+            hex(0xF1711F), hex(0xF1731D), hex(0xF2741C), hex(0xF3761B), hex(0xF37819),
+            hex(0xF47918), hex(0xF57B17), hex(0xF57D15), hex(0xF67E14), hex(0xF68013),
+            hex(0xF78212), hex(0xF78410), hex(0xF8850F), hex(0xF8870E), hex(0xF8890C),
+            hex(0xF98B0B), hex(0xF98C0A), hex(0xF98E09), hex(0xFA9008), hex(0xFA9207),
+            hex(0xFA9407), hex(0xFB9606), hex(0xFB9706), hex(0xFB9906), hex(0xFB9B06),
+            hex(0xFB9D07), hex(0xFC9F07), hex(0xFCA108), hex(0xFCA309), hex(0xFCA50A),
+            hex(0xFCA60C), hex(0xFCA80D), hex(0xFCAA0F), hex(0xFCAC11), hex(0xFCAE12),
+            hex(0xFCB014), hex(0xFCB216), hex(0xFCB418), hex(0xFBB61A), hex(0xFBB81D),
+            hex(0xFBBA1F), hex(0xFBBC21), hex(0xFBBE23), hex(0xFAC026), hex(0xFAC228),
+            hex(0xFAC42A), hex(0xFAC62D), hex(0xF9C72F), hex(0xF9C932), hex(0xF9CB35),
+            hex(0xF8CD37), hex(0xF8CF3A), hex(0xF7D13D), hex(0xF7D340), hex(0xF6D543),
+            hex(0xF6D746), hex(0xF5D949), hex(0xF5DB4C), hex(0xF4DD4F), hex(0xF4DF53),
+            hex(0xF4E156), hex(0xF3E35A), hex(0xF3E55D), hex(0xF2E661), hex(0xF2E865),
+            hex(0xF2EA69), hex(0xF1EC6D), hex(0xF1ED71), hex(0xF1EF75), hex(0xF1F179),
+            hex(0xF2F27D), hex(0xF2F482), hex(0xF3F586), hex(0xF3F68A), hex(0xF4F88E),
+            hex(0xF5F992), hex(0xF6FA96), hex(0xF8FB9A), hex(0xF9FC9D), hex(0xFAFDA1),
+            hex(0xFCFFA4),
+        ],
+    },
+    Builtin {
+        name: "plasma",
+        kind: ColormapKind::Sequential,
+        colors: &[
+            hex(0x0D0887), hex(0x100788), hex(0x130789), hex(0x16078A), hex(0x19068C),
+            hex(0x1B068D), hex(0x1D068E), hex(0x20068F), hex(0x220690), hex(0x240691),
+            hex(0x260591), hex(0x280592), hex(0x2A0593), hex(0x2C0594), hex(0x2E0595),
+            hex(0x2F0596), hex(0x310597), hex(0x330597), hex(0x350498), hex(0x370499),
+            hex(0x38049A), hex(0x3A049A), hex(0x3C049B), hex(0x3E049C), hex(0x3F049C),
+            hex(0x41049D), hex(0x43039E), hex(0x44039E), hex(0x46039F), hex(0x48039F),
+            hex(0x4903A0), hex(0x4B03A1), hex(0x4C02A1), hex(0x4E02A2), hex(0x5002A2),
+            hex(0x5102A3), hex(0x5302A3), hex(0x5502A4), hex(0x5601A4), hex(0x5801A4),
+            hex(0x5901A5), hex(0x5B01A5), hex(0x5C01A6), hex(0x5E01A6), hex(0x6001A6),
+            hex(0x6100A7), hex(0x6300A7), hex(0x6400A7), hex(0x6600A7), hex(0x6700A8),
+            hex(0x6900A8), hex(0x6A00A8), hex(0x6C00A8), hex(0x6E00A8), hex(0x6F00A8),
+            hex(0x7100A8), hex(0x7201A8), hex(0x7401A8), hex(0x7501A8), hex(0x7701A8),
+            hex(0x7801A8), hex(0x7A02A8), hex(0x7B02A8), hex(0x7D03A8), hex(0x7E03A8),
+            hex(0x8004A8), hex(0x8104A7), hex(0x8305A7), hex(0x8405A7), hex(0x8606A6),
+            hex(0x8707A6), hex(0x8808A6), hex(0x8A09A5), hex(0x8B0AA5), hex(0x8D0BA5),
+            hex(0x8E0CA4), hex(0x8F0DA4), hex(0x910EA3), hex(0x920FA3), hex(0x9410A2),
+            hex(0x9511A1), hex(0x9613A1), hex(0x9814A0), hex(0x99159F), hex(0x9A169F),
+            hex(0x9C179E), hex(0x9D189D), hex(0x9E199D), hex(0xA01A9C), hex(0xA11B9B),
+            hex(0xA21D9A), hex(0xA31E9A), hex(0xA51F99), hex(0xA62098), hex(0xA72197),
+            hex(0xA82296), hex(0xAA2395), hex(0xAB2494), hex(0xAC2694), hex(0xAD2793),
+            hex(0xAE2892), hex(0xB02991), hex(0xB12A90), hex(0xB22B8F), hex(0xB32C8E),
+            hex(0xB42E8D), hex(0xB52F8C), hex(0xB6308B), hex(0xB7318A), hex(0xB83289),
+            hex(0xBA3388), hex(0xBB3488), hex(0xBC3587), hex(0xBD3786), hex(0xBE3885),
+            hex(0xBF3984), hex(0xC03A83), hex(0xC13B82), hex(0xC23C81), hex(0xC33D80),
+            hex(0xC43E7F), hex(0xC5407E), hex(0xC6417D), hex(0xC7427C), hex(0xC8437B),
+            hex(0xC9447A), hex(0xCA457A), hex(0xCB4679), hex(0xCC4778), hex(0xCC4977),
+            hex(0xCD4A76), hex(0xCE4B75), hex(0xCF4C74), hex(0xD04D73), hex(0xD14E72),
+            hex(0xD24F71), hex(0xD35171), hex(0xD45270), hex(0xD5536F), hex(0xD5546E),
+            hex(0xD6556D), hex(0xD7566C), hex(0xD8576B), hex(0xD9586A), hex(0xDA5A6A),
+            hex(0xDA5B69), hex(0xDB5C68), hex(0xDC5D67), hex(0xDD5E66), hex(0xDE5F65),
+            hex(0xDE6164), hex(0xDF6263), hex(0xE06363), hex(0xE16462), hex(0xE26561),
+            hex(0xE26660), hex(0xE3685F), hex(0xE4695E), hex(0xE56A5D), hex(0xE56B5D),
+            hex(0xE66C5C), hex(0xE76E5B), hex(0xE76F5A), hex(0xE87059), hex(0xE97158),
+            hex(0xE97257), hex(0xEA7457), hex(0xEB7556), hex(0xEB7655), hex(0xEC7754),
+            hex(0xED7953), hex(0xED7A52), hex(0xEE7B51), hex(0xEF7C51), hex(0xEF7E50),
+            hex(0xF07F4F), hex(0xF0804E), hex(0xF1814D), hex(0xF1834C), hex(0xF2844B),
+            hex(0xF3854B), hex(0xF3874A), hex(0xF48849), hex(0xF48948), hex(0xF58B47),
+            hex(0xF58C46), hex(0xF68D45), hex(0xF68F44), hex(0xF79044), hex(0xF79143),
+            hex(0xF79342), hex(0xF89441), hex(0xF89540), hex(0xF9973F), hex(0xF9983E),
+            hex(0xF99A3E), hex(0xFA9B3D), hex(0xFA9C3C), hex(0xFA9E3B), hex(0xFB9F3A),
+            hex(0xFBA139), hex(0xFBA238), hex(0xFCA338), hex(0xFCA537), hex(0xFCA636),
+            hex(0xFCA835), hex(0xFCA934), hex(0xFDAB33), hex(0xFDAC33), hex(0xFDAE32),
+            hex(0xFDAF31), hex(0xFDB130), hex(0xFDB22F), hex(0xFDB42F), hex(0xFDB52E),
+            hex(0xFEB72D), hex(0xFEB82C), hex(0xFEBA2C), hex(0xFEBB2B), hex(0xFEBD2A),
+            hex(0xFEBE2A), hex(0xFEC029), hex(0xFDC229), hex(0xFDC328), hex(0xFDC527),
+            hex(0xFDC627), hex(0xFDC827), hex(0xFDCA26), hex(0xFDCB26), hex(0xFCCD25),
+            hex(0xFCCE25), hex(0xFCD025), hex(0xFCD225), hex(0xFBD324), hex(0xFBD524),
+            hex(0xFBD724), hex(0xFAD824), hex(0xFADA24), hex(0xF9DC24), hex(0xF9DD25),
+            hex(0xF8DF25), hex(0xF8E125), hex(0xF7E225), hex(0xF7E425), hex(0xF6E626),
+            hex(0xF6E826), hex(0xF5E926), hex(0xF5EB27), hex(0xF4ED27), hex(0xF3EE27),
+            hex(0xF3F027), hex(0xF2F227), hex(0xF1F426), hex(0xF1F525), hex(0xF0F724),
+            hex(0xF0F921),
+        ],
+    },
+    Builtin {
+        name: "cividis",
+        kind: ColormapKind::Sequential,
+        colors: &[
+            hex(0x00224E), hex(0x00234F), hex(0x002451), hex(0x002553), hex(0x002554),
+            hex(0x002656), hex(0x002758), hex(0x002859), hex(0x00285B), hex(0x00295D),
+            hex(0x002A5F), hex(0x002A61), hex(0x002B62), hex(0x002C64), hex(0x002C66),
+            hex(0x002D68), hex(0x002E6A), hex(0x002E6C), hex(0x002F6D), hex(0x00306F),
+            hex(0x003070), hex(0x003170), hex(0x003171), hex(0x013271), hex(0x053371),
+            hex(0x083370), hex(0x0C3470), hex(0x0F3570), hex(0x123570), hex(0x143670),
+            hex(0x163770), hex(0x18376F), hex(0x1A386F), hex(0x1C396F), hex(0x1E3A6F),
+            hex(0x203A6F), hex(0x213B6E), hex(0x233C6E), hex(0x243C6E), hex(0x263D6E),
+            hex(0x273E6E), hex(0x293F6E), hex(0x2A3F6D), hex(0x2B406D), hex(0x2D416D),
+            hex(0x2E416D), hex(0x2F426D), hex(0x31436D), hex(0x32436D), hex(0x33446D),
+            hex(0x34456C), hex(0x35456C), hex(0x36466C), hex(0x38476C), hex(0x39486C),
+            hex(0x3A486C), hex(0x3B496C), hex(0x3C4A6C), hex(0x3D4A6C), hex(0x3E4B6C),
+            hex(0x3F4C6C), hex(0x404C6C), hex(0x414D6C), hex(0x424E6C), hex(0x434E6C),
+            hex(0x444F6C), hex(0x45506C), hex(0x46516C), hex(0x47516C), hex(0x48526C),
+            hex(0x49536C), hex(0x4A536C), hex(0x4B546C), hex(0x4C556C), hex(0x4D556C),
+            hex(0x4E566C), hex(0x4F576C), hex(0x50576C), hex(0x51586D), hex(0x52596D),
+            hex(0x535A6D), hex(0x545A6D), hex(0x555B6D), hex(0x555C6D), hex(0x565C6D),
+            hex(0x575D6D), hex(0x585E6D), hex(0x595E6E), hex(0x5A5F6E), hex(0x5B606E),
+            hex(0x5C616E), hex(0x5D616E), hex(0x5E626E), hex(0x5E636F), hex(0x5F636F),
+            hex(0x60646F), hex(0x61656F), hex(0x62656F), hex(0x636670), hex(0x646770),
+            hex(0x656870), hex(0x656870), hex(0x666970), hex(0x676A71), hex(0x686A71),
+            hex(0x696B71), hex(0x6A6C71), hex(0x6B6D72), hex(0x6C6D72), hex(0x6C6E72),
+            hex(0x6D6F72), hex(0x6E6F73), hex(0x6F7073), hex(0x707173), hex(0x717274),
+            hex(0x727274), hex(0x727374), hex(0x737475), hex(0x747475), hex(0x757575),
+            hex(0x767676), hex(0x777776), hex(0x777777), hex(0x787877), hex(0x797977),
+            hex(0x7A7A78), hex(0x7B7A78), hex(0x7C7B78), hex(0x7D7C78), hex(0x7E7C78),
+            hex(0x7E7D78), hex(0x7F7E78), hex(0x807F78), hex(0x817F78), hex(0x828079),
+            hex(0x838179), hex(0x848279), hex(0x858279), hex(0x868379), hex(0x878478),
+            hex(0x888578), hex(0x898578), hex(0x8A8678), hex(0x8B8778), hex(0x8C8878),
+            hex(0x8D8878), hex(0x8E8978), hex(0x8F8A78), hex(0x908B78), hex(0x918B78),
+            hex(0x928C78), hex(0x928D78), hex(0x938E78), hex(0x948E77), hex(0x958F77),
+            hex(0x969077), hex(0x979177), hex(0x989277), hex(0x999277), hex(0x9A9376),
+            hex(0x9B9476), hex(0x9C9576), hex(0x9D9576), hex(0x9E9676), hex(0x9F9775),
+            hex(0xA09875), hex(0xA19975), hex(0xA29975), hex(0xA39A74), hex(0xA49B74),
+            hex(0xA59C74), hex(0xA69C74), hex(0xA79D73), hex(0xA89E73), hex(0xA99F73),
+            hex(0xAAA073), hex(0xABA072), hex(0xACA172), hex(0xADA272), hex(0xAEA371),
+            hex(0xAFA471), hex(0xB0A571), hex(0xB1A570), hex(0xB3A670), hex(0xB4A76F),
+            hex(0xB5A86F), hex(0xB6A96F), hex(0xB7A96E), hex(0xB8AA6E), hex(0xB9AB6D),
+            hex(0xBAAC6D), hex(0xBBAD6D), hex(0xBCAE6C), hex(0xBDAE6C), hex(0xBEAF6B),
+            hex(0xBFB06B), hex(0xC0B16A), hex(0xC1B26A), hex(0xC2B369), hex(0xC3B369),
+            hex(0xC4B468), hex(0xC5B568), hex(0xC6B667), hex(0xC7B767), hex(0xC8B866),
+            hex(0xC9B965), hex(0xCBB965), hex(0xCCBA64), hex(0xCDBB63), hex(0xCEBC63),
+            hex(0xCFBD62), hex(0xD0BE62), hex(0xD1BF61), hex(0xD2C060), hex(0xD3C05F),
+            hex(0xD4C15F), hex(0xD5C25E), hex(0xD6C35D), hex(0xD7C45C), hex(0xD9C55C),
+            hex(0xDAC65B), hex(0xDBC75A), hex(0xDCC859), hex(0xDDC858), hex(0xDEC958),
+            hex(0xDFCA57), hex(0xE0CB56), hex(0xE1CC55), hex(0xE2CD54), hex(0xE4CE53),
+            hex(0xE5CF52), hex(0xE6D051), hex(0xE7D150), hex(0xE8D24F), hex(0xE9D34E),
+            hex(0xEAD34C), hex(0xEBD44B), hex(0xEDD54A), hex(0xEED649), hex(0xEFD748),
+            hex(0xF0D846), hex(0xF1D945), hex(0xF2DA44), hex(0xF3DB42), hex(0xF5DC41),
+            hex(0xF6DD3F), hex(0xF7DE3E), hex(0xF8DF3C), hex(0xF9E03A), hex(0xFBE138),
+            hex(0xFCE236), hex(0xFDE334), hex(0xFEE434), hex(0xFEE535), hex(0xFEE636),
+            hex(0xFEE838),
+        ],
+    },
+    Builtin {
+        name: "turbo",
+        kind: ColormapKind::Rainbow,
+        colors: &[
+            hex(0x30123B), hex(0x321543), hex(0x33184A), hex(0x341B51), hex(0x351E58),
+            hex(0x36215F), hex(0x372466), hex(0x38276D), hex(0x392A73), hex(0x3A2D79),
+            hex(0x3B2F80), hex(0x3C3286), hex(0x3D358B), hex(0x3E3891), hex(0x3F3B97),
+            hex(0x3F3E9C), hex(0x4040A2), hex(0x4143A7), hex(0x4146AC), hex(0x4249B1),
+            hex(0x424BB5), hex(0x434EBA), hex(0x4451BF), hex(0x4454C3), hex(0x4456C7),
+            hex(0x4559CB), hex(0x455CCF), hex(0x455ED3), hex(0x4661D6), hex(0x4664DA),
+            hex(0x4666DD), hex(0x4669E0), hex(0x466BE3), hex(0x476EE6), hex(0x4771E9),
+            hex(0x4773EB), hex(0x4776EE), hex(0x4778F0), hex(0x477BF2), hex(0x467DF4),
+            hex(0x4680F6), hex(0x4682F8), hex(0x4685FA), hex(0x4687FB), hex(0x458AFC),
+            hex(0x458CFD), hex(0x448FFE), hex(0x4391FE), hex(0x4294FF), hex(0x4196FF),
+            hex(0x4099FF), hex(0x3E9BFE), hex(0x3D9EFE), hex(0x3BA0FD), hex(0x3AA3FC),
+            hex(0x38A5FB), hex(0x37A8FA), hex(0x35ABF8), hex(0x33ADF7), hex(0x31AFF5),
+            hex(0x2FB2F4), hex(0x2EB4F2), hex(0x2CB7F0), hex(0x2AB9EE), hex(0x28BCEB),
+            hex(0x27BEE9), hex(0x25C0E7), hex(0x23C3E4), hex(0x22C5E2), hex(0x20C7DF),
+            hex(0x1FC9DD), hex(0x1ECBDA), hex(0x1CCDD8), hex(0x1BD0D5), hex(0x1AD2D2),
+            hex(0x1AD4D0), hex(0x19D5CD), hex(0x18D7CA), hex(0x18D9C8), hex(0x18DBC5),
+            hex(0x18DDC2), hex(0x18DEC0), hex(0x18E0BD), hex(0x19E2BB), hex(0x19E3B9),
+            hex(0x1AE4B6), hex(0x1CE6B4), hex(0x1DE7B2), hex(0x1FE9AF), hex(0x20EAAC),
+            hex(0x22EBAA), hex(0x25ECA7), hex(0x27EEA4), hex(0x2AEFA1), hex(0x2CF09E),
+            hex(0x2FF19B), hex(0x32F298), hex(0x35F394), hex(0x38F491), hex(0x3CF58E),
+            hex(0x3FF68A), hex(0x43F787), hex(0x46F884), hex(0x4AF880), hex(0x4EF97D),
+            hex(0x52FA7A), hex(0x55FA76), hex(0x59FB73), hex(0x5DFC6F), hex(0x61FC6C),
+            hex(0x65FD69), hex(0x69FD66), hex(0x6DFE62), hex(0x71FE5F), hex(0x75FE5C),
+            hex(0x79FE59), hex(0x7DFF56), hex(0x80FF53), hex(0x84FF51), hex(0x88FF4E),
+            hex(0x8BFF4B), hex(0x8FFF49), hex(0x92FF47), hex(0x96FE44), hex(0x99FE42),
+            hex(0x9CFE40), hex(0x9FFD3F), hex(0xA1FD3D), hex(0xA4FC3C), hex(0xA7FC3A),
+            hex(0xA9FB39), hex(0xACFB38), hex(0xAFFA37), hex(0xB1F936), hex(0xB4F836),
+            hex(0xB7F735), hex(0xB9F635), hex(0xBCF534), hex(0xBEF434), hex(0xC1F334),
+            hex(0xC3F134), hex(0xC6F034), hex(0xC8EF34), hex(0xCBED34), hex(0xCDEC34),
+            hex(0xD0EA34), hex(0xD2E935), hex(0xD4E735), hex(0xD7E535), hex(0xD9E436),
+            hex(0xDBE236), hex(0xDDE037), hex(0xDFDF37), hex(0xE1DD37), hex(0xE3DB38),
+            hex(0xE5D938), hex(0xE7D739), hex(0xE9D539), hex(0xEBD339), hex(0xECD13A),
+            hex(0xEECF3A), hex(0xEFCD3A), hex(0xF1CB3A), hex(0xF2C93A), hex(0xF4C73A),
+            hex(0xF5C53A), hex(0xF6C33A), hex(0xF7C13A), hex(0xF8BE39), hex(0xF9BC39),
+            hex(0xFABA39), hex(0xFBB838), hex(0xFBB637), hex(0xFCB336), hex(0xFCB136),
+            hex(0xFDAE35), hex(0xFDAC34), hex(0xFEA933), hex(0xFEA732), hex(0xFEA431),
+            hex(0xFEA130), hex(0xFE9E2F), hex(0xFE9B2D), hex(0xFE992C), hex(0xFE962B),
+            hex(0xFE932A), hex(0xFE9029), hex(0xFD8D27), hex(0xFD8A26), hex(0xFC8725),
+            hex(0xFC8423), hex(0xFB8122), hex(0xFB7E21), hex(0xFA7B1F), hex(0xF9781E),
+            hex(0xF9751D), hex(0xF8721C), hex(0xF76F1A), hex(0xF66C19), hex(0xF56918),
+            hex(0xF46617), hex(0xF36315), hex(0xF26014), hex(0xF15D13), hex(0xF05B12),
+            hex(0xEF5811), hex(0xED5510), hex(0xEC530F), hex(0xEB500E), hex(0xEA4E0D),
+            hex(0xE84B0C), hex(0xE7490C), hex(0xE5470B), hex(0xE4450A), hex(0xE2430A),
+            hex(0xE14109), hex(0xDF3F08), hex(0xDD3D08), hex(0xDC3B07), hex(0xDA3907),
+            hex(0xD83706), hex(0xD63506), hex(0xD43305), hex(0xD23105), hex(0xD02F05),
+            hex(0xCE2D04), hex(0xCC2B04), hex(0xCA2A04), hex(0xC82803), hex(0xC52603),
+            hex(0xC32503), hex(0xC12302), hex(0xBE2102), hex(0xBC2002), hex(0xB91E02),
+            hex(0xB71D02), hex(0xB41B01), hex(0xB21A01), hex(0xAF1801), hex(0xAC1701),
+            hex(0xA91601), hex(0xA71401), hex(0xA41301), hex(0xA11201), hex(0x9E1001),
+            hex(0x9B0F01), hex(0x980E01), hex(0x950D01), hex(0x920B01), hex(0x8E0A01),
+            hex(0x8B0902), hex(0x880802), hex(0x850702), hex(0x810602), hex(0x7E0502),
+            hex(0x7A0403),
+        ],
+    },
+    Builtin {
+        name: "batlow",
+        kind: ColormapKind::Sequential,
+        colors: &[
+            hex(0x011959), hex(0x0E3E61), hex(0x1C5A62), hex(0x3C6D56), hex(0x687B3E),
+            hex(0x9D892B), hex(0xD29243), hex(0xF8A17B), hex(0xFDB7BC), hex(0xFACCFA),
+        ],
+    },
+    Builtin {
+        name: "roma",
+        kind: ColormapKind::Diverging,
+        colors: &[
+            hex(0x7E1900), hex(0x9C5B1E), hex(0xB7913A), hex(0xD5C76D), hex(0xE7EEB4),
+            hex(0xADE3D1), hex(0x65BBD0), hex(0x3F8CC0), hex(0x2960AA), hex(0x1A3399),
+        ],
+    },
+    Builtin {
+        name: "vik",
+        kind: ColormapKind::Diverging,
+        colors: &[
+            hex(0x001261), hex(0x033E7D), hex(0x1E6F9D), hex(0x71A8C4), hex(0xC9DDE7),
+            hex(0xEACEBD), hex(0xD29773), hex(0xBC6532), hex(0x9E3009), hex(0x590008),
+        ],
+    },
+];
@@ -0,0 +1,243 @@
+//! Writers for plotting-library colormap formats.
+
+use std::fmt::{self, Write};
+use std::str::FromStr;
+
+use super::Colormap;
+use crate::RGB;
+
+/// A colormap file format understood by a plotting library.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// A Python module defining a matplotlib `ListedColormap`.
+    Matplotlib,
+    /// A ParaView XML color map preset.
+    ParaviewXml,
+    /// A ParaView JSON color map preset.
+    ParaviewJson,
+    /// A gnuplot `set palette defined` command.
+    Gnuplot,
+    /// A GMT color palette table (`.cpt`).
+    Gmt,
+    /// An NCL color table (`.rgb`).
+    Ncl,
+}
+
+impl ExportFormat {
+    /// The conventional file extension, without the leading dot.
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Matplotlib => "py",
+            ExportFormat::ParaviewXml => "xml",
+            ExportFormat::ParaviewJson => "json",
+            ExportFormat::Gnuplot => "gp",
+            ExportFormat::Gmt => "cpt",
+            ExportFormat::Ncl => "rgb",
+        }
+    }
+}
+
+impl FromStr for ExportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "matplotlib" | "mpl" => Ok(ExportFormat::Matplotlib),
+            "paraview-xml" | "paraview" => Ok(ExportFormat::ParaviewXml),
+            "paraview-json" => Ok(ExportFormat::ParaviewJson),
+            "gnuplot" => Ok(ExportFormat::Gnuplot),
+            "gmt" | "cpt" => Ok(ExportFormat::Gmt),
+            "ncl" => Ok(ExportFormat::Ncl),
+            _ => Err(format!("Unknown export format '{}'", s)),
+        }
+    }
+}
+
+pub(crate) fn unit(rgb: RGB) -> [f64; 3] {
+    [rgb.r as f64 / 255.0, rgb.g as f64 / 255.0, rgb.b as f64 / 255.0]
+}
+
+/// Turns a colormap name into a valid Python identifier.
+pub(crate) fn identifier(name: &str) -> String {
+    let mut ident: String = name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    if ident.is_empty() || ident.starts_with(|c: char| c.is_ascii_digit()) {
+        ident.insert(0, '_');
+    }
+    ident
+}
+
+pub(crate) fn escape_xml(s: &str) -> String {
+    s.replace('&', "&amp;").replace('"', "&quot;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+/// Escapes a string for a double-quoted JSON or Python literal.
+pub(crate) fn escape_json(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c < ' ' => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Flattens a name onto one line for a `#` comment header, so that line
+/// breaks in it cannot start data lines.
+pub(crate) fn comment(s: &str) -> String {
+    s.chars().map(|c| if c.is_control() { ' ' } else { c }).collect()
+}
+
+impl Colormap {
+    /// Returns `n` evenly spaced samples of the colormap in the given format.
+    pub fn export(&self, format: ExportFormat, n: usize) -> String {
+        let mut out = String::new();
+        self.write_export(&mut out, format, n)
+            .expect("writing to a String cannot fail");
+        out
+    }
+
+    /// Writes `n` evenly spaced samples of the colormap in the given format.
+    pub fn write_export(&self, out: &mut impl Write, format: ExportFormat, n: usize) -> fmt::Result {
+        let colors = self.discretize(n.max(2));
+        let last = (colors.len() - 1) as f64;
+
+        match format {
+            ExportFormat::Matplotlib => {
+                let ident = identifier(&self.name);
+                writeln!(out, "from matplotlib.colors import ListedColormap\n")?;
+                writeln!(out, "{}_data = [", ident)?;
+                for color in &colors {
+                    let [r, g, b] = unit(*color);
+                    writeln!(out, "    [{:.6}, {:.6}, {:.6}],", r, g, b)?;
+                }
+                writeln!(out, "]\n")?;
+                writeln!(out, "{} = ListedColormap({}_data, name=\"{}\")",
+                    ident, ident, escape_json(&self.name))?;
+            },
+            ExportFormat::ParaviewXml => {
+                writeln!(out, "<ColorMaps>")?;
+                writeln!(out, "  <ColorMap space=\"RGB\" indexedLookup=\"false\" name=\"{}\">",
+                    escape_xml(&self.name))?;
+                for (i, color) in colors.iter().enumerate() {
+                    let [r, g, b] = unit(*color);
+                    writeln!(out,
+                        "    <Point x=\"{:.6}\" o=\"1\" r=\"{:.6}\" g=\"{:.6}\" b=\"{:.6}\"/>",
+                        i as f64 / last, r, g, b)?;
+                }
+                writeln!(out, "  </ColorMap>")?;
+                writeln!(out, "</ColorMaps>")?;
+            },
+            ExportFormat::ParaviewJson => {
+                writeln!(out, "[")?;
+                writeln!(out, "  {{")?;
+                writeln!(out, "    \"ColorSpace\" : \"RGB\",")?;
+                writeln!(out, "    \"Name\" : \"{}\",", escape_json(&self.name))?;
+                writeln!(out, "    \"RGBPoints\" : [")?;
+                for (i, color) in colors.iter().enumerate() {
+                    let [r, g, b] = unit(*color);
+                    let sep = if i + 1 < colors.len() { "," } else { "" };
+                    writeln!(out, "      {:.6}, {:.6}, {:.6}, {:.6}{}",
+                        i as f64 / last, r, g, b, sep)?;
+                }
+                writeln!(out, "    ]")?;
+                writeln!(out, "  }}")?;
+                writeln!(out, "]")?;
+            },
+            ExportFormat::Gnuplot => {
+                writeln!(out, "# {}", comment(&self.name))?;
+                writeln!(out, "set palette defined ( \\")?;
+                for (i, color) in colors.iter().enumerate() {
+                    let sep = if i + 1 < colors.len() { ", \\" } else { " )" };
+                    writeln!(out, "    {:.6} '{}'{}", i as f64 / last, color.to_hex(), sep)?;
+                }
+            },
+            ExportFormat::Gmt => {
+                writeln!(out, "# {}", comment(&self.name))?;
+                writeln!(out, "# COLOR_MODEL = RGB")?;
+                for (i, pair) in colors.windows(2).enumerate() {
+                    let (a, b) = (pair[0], pair[1]);
+                    writeln!(out, "{:.6}\t{}/{}/{}\t{:.6}\t{}/{}/{}",
+                        i as f64 / last, a.r, a.g, a.b,
+                        (i + 1) as f64 / last, b.r, b.g, b.b)?;
+                }
+                let (first, end) = (colors[0], colors[colors.len() - 1]);
+                writeln!(out, "B\t{}/{}/{}", first.r, first.g, first.b)?;
+                writeln!(out, "F\t{}/{}/{}", end.r, end.g, end.b)?;
+                writeln!(out, "N\t128/128/128")?;
+            },
+            ExportFormat::Ncl => {
+                writeln!(out, "ncolors={}", colors.len())?;
+                writeln!(out, "# r   g   b")?;
+                for color in &colors {
+                    writeln!(out, "{:<3} {:<3} {}", color.r, color.g, color.b)?;
+                }
+            },
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::colormap::ImportFormat;
+    use crate::{ColormapKind, RGB};
+
+    const AWKWARD: &str = "my \"map\"\\\n\tB\t0/0/0\u{1}";
+
+    fn awkward() -> Colormap {
+        Colormap { name: AWKWARD.to_string(), ..Colormap::builtin("viridis").unwrap() }
+    }
+
+    #[test]
+    fn escapes_json_strings() {
+        assert_eq!(escape_json("plain"), "plain");
+        assert_eq!(escape_json(AWKWARD), "my \\\"map\\\"\\\\\\n\\tB\\t0/0/0\\u0001");
+    }
+
+    #[test]
+    fn comment_headers_stay_on_one_line() {
+        for format in [ExportFormat::Gnuplot, ExportFormat::Gmt] {
+            let text = awkward().export(format, 8);
+            let header = text.lines().next().unwrap();
+            assert_eq!(header, "# my \"map\"\\  B 0/0/0 ");
+            assert!(!text.lines().skip(1).any(|line| line.contains("map")));
+        }
+    }
+
+    #[test]
+    fn cpt_round_trips() {
+        let colormap = awkward();
+        let cpt = colormap.export(ExportFormat::Gmt, 256);
+        let imported = Colormap::import(cpt.as_bytes(), ImportFormat::Cpt, "viridis").unwrap();
+        assert_eq!(imported.colors, colormap.discretize(256));
+        assert_eq!(imported.kind, ColormapKind::Sequential);
+    }
+
+    #[test]
+    fn paraview_json_round_trips() {
+        let colormap = awkward();
+        let json = colormap.export(ExportFormat::ParaviewJson, 256);
+        let imported = Colormap::import(json.as_bytes(), ImportFormat::ParaviewJson, "x").unwrap();
+        assert_eq!(imported.name, AWKWARD);
+        assert_eq!(imported.colors, colormap.discretize(256));
+    }
+
+    #[test]
+    fn python_literal_is_escaped() {
+        let text = Colormap::new(AWKWARD, ColormapKind::Sequential, vec![RGB::new(0, 0, 0)])
+            .export(ExportFormat::Matplotlib, 2);
+        let last = text.lines().last().unwrap();
+        assert_eq!(last, "my__map____B_0_0_0_ = ListedColormap(my__map____B_0_0_0__data, \
+            name=\"my \\\"map\\\"\\\\\\n\\tB\\t0/0/0\\u0001\")");
+    }
+}
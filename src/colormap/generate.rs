@@ -0,0 +1,270 @@
+//! Generators for custom colormaps.
+
+use std::str::FromStr;
+
+use super::{Colormap, ColormapKind, InterpolationSpace};
+use crate::oklab::{self, max_chroma};
+use crate::xyz::lerp;
+use crate::{Cam16Ucs, LinearRGB, Oklab, RGB};
+
+/// Anchor colors of a four-phase cyclic colormap: magenta, yellow, green and
+/// blue, with the lightness alternating around the cycle so that the four
+/// quadrants of the phase circle are easy to tell apart.
+pub const FOUR_PHASE: [RGB; 4] = [
+    RGB::new(0xe0, 0x4f, 0xd8),
+    RGB::new(0xf2, 0xd6, 0x4b),
+    RGB::new(0x4f, 0xb3, 0x4f),
+    RGB::new(0x3f, 0x6a, 0xe0),
+];
+
+/// The neutral color at the midpoint of a diverging colormap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Neutral {
+    #[default]
+    White,
+    /// Middle gray, `#808080`.
+    Gray,
+    Black,
+}
+
+impl Neutral {
+    /// Returns the neutral color.
+    pub fn rgb(self) -> RGB {
+        match self {
+            Neutral::White => RGB::new(255, 255, 255),
+            Neutral::Gray => RGB::new(128, 128, 128),
+            Neutral::Black => RGB::new(0, 0, 0),
+        }
+    }
+}
+
+impl FromStr for Neutral {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "white" => Ok(Neutral::White),
+            "gray" | "grey" => Ok(Neutral::Gray),
+            "black" => Ok(Neutral::Black),
+            _ => Err(format!("Unknown neutral color '{}'", s)),
+        }
+    }
+}
+
+/// Returns the coordinates of `rgb` in a perceptual space, with lightness
+/// first, or `None` if `space` has no perceptual lightness axis.
+fn coordinates(space: InterpolationSpace, rgb: RGB) -> Option<[f64; 3]> {
+    match space {
+        InterpolationSpace::Oklab => {
+            let lab = rgb.to_oklab();
+            Some([lab.l, lab.a, lab.b])
+        },
+        InterpolationSpace::Cam16Ucs => {
+            let ucs = rgb.to_cam16_ucs();
+            Some([ucs.j, ucs.a, ucs.b])
+        },
+        InterpolationSpace::Srgb | InterpolationSpace::LinearRgb => None,
+    }
+}
+
+/// Inverse of [`coordinates`], without clipping.
+fn to_linear(space: InterpolationSpace, [l, a, b]: [f64; 3]) -> LinearRGB {
+    match space {
+        InterpolationSpace::Cam16Ucs => Cam16Ucs::new(l, a, b).to_linear(),
+        _ => Oklab::new(l, a, b).to_linear(),
+    }
+}
+
+/// Inverse of [`coordinates`], clipping to the sRGB gamut.
+fn from_coordinates(space: InterpolationSpace, point: [f64; 3]) -> RGB {
+    to_linear(space, point).to_rgb()
+}
+
+/// Returns true if `point` lies in the sRGB gamut.
+fn fits(space: InterpolationSpace, point: [f64; 3]) -> bool {
+    oklab::fits(to_linear(space, point))
+}
+
+/// Moves `point` to lightness `l`, reducing its chroma relative to `center`
+/// until it fits in the sRGB gamut.
+fn with_lightness(space: InterpolationSpace, point: [f64; 3], center: [f64; 3], l: f64) -> [f64; 3] {
+    let at = |s: f64| [l, center[1] + (point[1] - center[1]) * s, center[2] + (point[2] - center[2]) * s];
+    at(max_chroma(1.0, |s| fits(space, at(s))))
+}
+
+impl Colormap {
+    /// Builds a sequential colormap of `n` colors passing through `anchors`.
+    ///
+    /// The anchors are joined by straight lines in `space`, which must be
+    /// [`Oklab`](InterpolationSpace::Oklab) or
+    /// [`Cam16Ucs`](InterpolationSpace::Cam16Ucs), and the path is
+    /// re-parameterized so that lightness changes linearly along the map.
+    /// This requires the anchors to be ordered by strictly increasing or
+    /// strictly decreasing lightness.
+    pub fn sequential(
+        name: impl Into<String>,
+        anchors: &[RGB],
+        space: InterpolationSpace,
+        n: usize,
+    ) -> Result<Colormap, String> {
+        if anchors.len() < 2 {
+            return Err("A sequential colormap needs at least two anchor colors".to_string());
+        }
+        let points = anchors.iter()
+            .map(|&rgb| coordinates(space, rgb))
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| "Sequential colormaps must be interpolated in oklab or cam16".to_string())?;
+
+        let rising = points[1][0] > points[0][0];
+        if points.windows(2).any(|w| w[1][0] == w[0][0] || (w[1][0] > w[0][0]) != rising) {
+            return Err("Anchor colors must be ordered by strictly increasing or decreasing lightness"
+                .to_string());
+        }
+
+        let n = n.max(2);
+        let (first, last) = (points[0][0], points[points.len() - 1][0]);
+        let colors = (0..n)
+            .map(|k| {
+                let l = first + (last - first) * k as f64 / (n - 1) as f64;
+                let w = points.windows(2)
+                    .find(|w| (l - w[0][0]) * (l - w[1][0]) <= 0.0)
+                    .unwrap_or(&points[points.len() - 2..]);
+                let f = ((l - w[0][0]) / (w[1][0] - w[0][0])).clamp(0.0, 1.0);
+                from_coordinates(space, lerp(w[0], w[1], f))
+            })
+            .collect();
+
+        Ok(Colormap::new(name, ColormapKind::Sequential, colors).with_interpolation(space))
+    }
+
+    /// Builds a diverging colormap of `n` colors running from `from` through
+    /// a `neutral` midpoint to `to`.
+    ///
+    /// Both endpoints are moved to their mean lightness and to the smaller of
+    /// their two chromas, so the arms have symmetric lightness and the same
+    /// perceptual length in `space`, which must be
+    /// [`Oklab`](InterpolationSpace::Oklab) or
+    /// [`Cam16Ucs`](InterpolationSpace::Cam16Ucs). Each arm is a straight
+    /// line in that space, sampled at even steps.
+    pub fn diverging(
+        name: impl Into<String>,
+        from: RGB,
+        to: RGB,
+        neutral: Neutral,
+        space: InterpolationSpace,
+        n: usize,
+    ) -> Result<Colormap, String> {
+        let (Some(start), Some(end), Some(center)) = (
+            coordinates(space, from),
+            coordinates(space, to),
+            coordinates(space, neutral.rgb()),
+        ) else {
+            return Err("Diverging colormaps must be interpolated in oklab or cam16".to_string());
+        };
+
+        let l = (start[0] + end[0]) / 2.0;
+        let start = with_lightness(space, start, center, l);
+        let end = with_lightness(space, end, center, l);
+
+        // Equalize chroma relative to the neutral axis
+        let chroma = |p: [f64; 3]| (p[1] - center[1]).hypot(p[2] - center[2]);
+        let target = chroma(start).min(chroma(end));
+        let scale = |p: [f64; 3]| {
+            let s = if chroma(p) > 0.0 { target / chroma(p) } else { 0.0 };
+            [p[0], center[1] + (p[1] - center[1]) * s, center[2] + (p[2] - center[2]) * s]
+        };
+        let (start, end) = (scale(start), scale(end));
+
+        let n = n.max(2);
+        let colors = (0..n)
+            .map(|k| {
+                let t = k as f64 / (n - 1) as f64;
+                let point = if t < 0.5 {
+                    lerp(start, center, t * 2.0)
+                } else {
+                    lerp(center, end, t * 2.0 - 1.0)
+                };
+                from_coordinates(space, with_lightness(space, point, center, point[0]))
+            })
+            .collect();
+
+        Ok(Colormap::new(name, ColormapKind::Diverging, colors).with_interpolation(space))
+    }
+
+    /// Builds a cyclic colormap of `n` colors that runs once around the hue
+    /// circle at constant lightness and chroma.
+    ///
+    /// `lightness` is in `[0, 1]`, scaled to the lightness axis of `space`,
+    /// which must be [`Oklab`](InterpolationSpace::Oklab) (giving an Oklch
+    /// hue circle) or [`Cam16Ucs`](InterpolationSpace::Cam16Ucs). The chroma
+    /// is the largest at which every hue fits in the sRGB gamut. The first
+    /// and last colors are identical.
+    pub fn hue_circle(
+        name: impl Into<String>,
+        lightness: f64,
+        space: InterpolationSpace,
+        n: usize,
+    ) -> Result<Colormap, String> {
+        let scale = match space {
+            InterpolationSpace::Oklab => 1.0,
+            InterpolationSpace::Cam16Ucs => 100.0,
+            _ => return Err("Hue circles must be built in oklab or cam16".to_string()),
+        };
+        let l = lightness.clamp(0.0, 1.0) * scale;
+        let at = |c: f64, h: f64| [l, c * h.to_radians().cos(), c * h.to_radians().sin()];
+
+        let chroma = max_chroma(scale / 2.0, |c| (0..360).all(|h| fits(space, at(c, h as f64))));
+
+        let n = n.max(2);
+        let colors = (0..n)
+            .map(|k| from_coordinates(space, at(chroma, 360.0 * k as f64 / (n - 1) as f64)))
+            .collect();
+        Ok(Colormap::new(name, ColormapKind::Cyclic, colors).with_interpolation(space))
+    }
+
+    /// Builds a cyclic colormap of `n` colors that visits each of `anchors`
+    /// in turn and returns to the first, such as [`FOUR_PHASE`].
+    ///
+    /// The anchors are joined by straight lines in `space`, which must be
+    /// [`Oklab`](InterpolationSpace::Oklab) or
+    /// [`Cam16Ucs`](InterpolationSpace::Cam16Ucs), and sampled at equal
+    /// perceptual steps along the closed path. The first and last colors are
+    /// identical.
+    pub fn cyclic(
+        name: impl Into<String>,
+        anchors: &[RGB],
+        space: InterpolationSpace,
+        n: usize,
+    ) -> Result<Colormap, String> {
+        if anchors.len() < 2 {
+            return Err("A cyclic colormap needs at least two anchor colors".to_string());
+        }
+        let mut points = anchors.iter()
+            .map(|&rgb| coordinates(space, rgb))
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| "Cyclic colormaps must be interpolated in oklab or cam16".to_string())?;
+        points.push(points[0]);
+
+        let distance = |a: [f64; 3], b: [f64; 3]| {
+            ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) + (a[2] - b[2]).powi(2)).sqrt()
+        };
+        let lengths: Vec<f64> = points.windows(2).map(|w| distance(w[0], w[1])).collect();
+        let total: f64 = lengths.iter().sum();
+
+        let n = n.max(2);
+        let colors = (0..n)
+            .map(|k| {
+                let mut s = total * k as f64 / (n - 1) as f64;
+                let mut i = 0;
+                while i + 1 < lengths.len() && s > lengths[i] {
+                    s -= lengths[i];
+                    i += 1;
+                }
+                let f = if lengths[i] > 0.0 { (s / lengths[i]).clamp(0.0, 1.0) } else { 0.0 };
+                let point = lerp(points[i], points[i + 1], f);
+                from_coordinates(space, with_lightness(space, point, [point[0], 0.0, 0.0], point[0]))
+            })
+            .collect();
+        Ok(Colormap::new(name, ColormapKind::Cyclic, colors).with_interpolation(space))
+    }
+}
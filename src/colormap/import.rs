@@ -0,0 +1,469 @@
+//! Readers for colormap files produced by other tools.
+
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+use super::{Colormap, ColormapKind};
+use crate::{HSV, RGB};
+
+/// Number of evenly spaced control colors imported maps are resampled to.
+const RESAMPLE: usize = 256;
+
+/// A colormap file format that can be imported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportFormat {
+    /// A GMT color palette table (`.cpt`).
+    Cpt,
+    /// A ParaView JSON color map preset.
+    ParaviewJson,
+    /// Rows of `r,g,b` or `x,r,g,b` floats in `[0, 1]` (or `[0, 255]`).
+    Csv,
+    /// An ImageJ lookup table, either binary or text.
+    ImageJLut,
+}
+
+impl ImportFormat {
+    /// Guesses the format from a file extension.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let ext = path.extension()?.to_str()?.to_ascii_lowercase();
+        match ext.as_str() {
+            "cpt" => Some(ImportFormat::Cpt),
+            "json" => Some(ImportFormat::ParaviewJson),
+            "csv" | "txt" | "dat" => Some(ImportFormat::Csv),
+            "lut" => Some(ImportFormat::ImageJLut),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for ImportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "cpt" | "gmt" => Ok(ImportFormat::Cpt),
+            "paraview-json" | "json" => Ok(ImportFormat::ParaviewJson),
+            "csv" => Ok(ImportFormat::Csv),
+            "lut" | "imagej" => Ok(ImportFormat::ImageJLut),
+            _ => Err(format!("Unknown import format '{}'", s)),
+        }
+    }
+}
+
+/// An error from importing a colormap file.
+#[derive(Debug)]
+pub enum ImportError {
+    /// The file could not be read.
+    Io(io::Error),
+    /// The file format could not be determined from its extension.
+    UnknownFormat,
+    /// The contents are malformed. `line` is 1-based, or 0 when the problem
+    /// is not tied to a single line.
+    Parse { line: usize, message: String },
+}
+
+impl ImportError {
+    fn parse(line: usize, message: impl Into<String>) -> Self {
+        ImportError::Parse { line, message: message.into() }
+    }
+}
+
+impl fmt::Display for ImportError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ImportError::Io(e) => write!(f, "{}", e),
+            ImportError::UnknownFormat => write!(f, "Unrecognized colormap file extension"),
+            ImportError::Parse { line: 0, message } => write!(f, "{}", message),
+            ImportError::Parse { line, message } => write!(f, "Line {}: {}", line, message),
+        }
+    }
+}
+
+impl Error for ImportError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ImportError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ImportError {
+    fn from(e: io::Error) -> Self {
+        ImportError::Io(e)
+    }
+}
+
+impl Colormap {
+    /// Loads a colormap file, choosing the format by extension and naming the
+    /// colormap after the file stem.
+    pub fn load(path: &Path) -> Result<Colormap, ImportError> {
+        let format = ImportFormat::from_path(path).ok_or(ImportError::UnknownFormat)?;
+        let name = path.file_stem().and_then(|s| s.to_str()).unwrap_or("imported");
+        Colormap::import(&std::fs::read(path)?, format, name)
+    }
+
+    /// Parses colormap file contents in the given format.
+    ///
+    /// Maps with unevenly spaced stops are resampled to 256 control colors.
+    pub fn import(data: &[u8], format: ImportFormat, name: &str) -> Result<Colormap, ImportError> {
+        if format == ImportFormat::ImageJLut {
+            if let Some(colormap) = binary_lut(data, name) {
+                return Ok(colormap);
+            }
+        }
+
+        let text = std::str::from_utf8(data)
+            .map_err(|_| ImportError::parse(0, "File is not valid UTF-8 text"))?;
+        let (name, stops) = match format {
+            ImportFormat::Cpt => (name.to_string(), cpt(text)?),
+            ImportFormat::ParaviewJson => paraview_json(text, name)?,
+            ImportFormat::Csv | ImportFormat::ImageJLut => (name.to_string(), table(text)?),
+        };
+        let mut colormap = Colormap::from_stops(name, ColormapKind::Sequential, &stops, RESAMPLE)
+            .ok_or_else(|| ImportError::parse(0, "The file contains no colors"))?;
+        colormap.kind = infer_kind(&colormap.colors);
+        Ok(colormap)
+    }
+}
+
+/// Guesses the kind of an imported colormap, since none of the formats
+/// record it.
+///
+/// Maps whose ends match are cyclic. Maps whose lightness peaks or dips well
+/// inside the range, at a color less saturated than either end, are
+/// diverging. Anything else is treated as sequential.
+fn infer_kind(colors: &[RGB]) -> ColormapKind {
+    let n = colors.len();
+    if n < 3 {
+        return ColormapKind::Sequential;
+    }
+    let (first, last) = (colors[0].to_lab(), colors[n - 1].to_lab());
+    if first.delta_e_2000(last) < 3.0 {
+        return ColormapKind::Cyclic;
+    }
+
+    let lch: Vec<_> = colors.iter().map(|c| c.to_lch()).collect();
+    let inner = &lch[n / 4..n - n / 4];
+    let brightest = inner.iter().max_by(|a, b| a.l.total_cmp(&b.l));
+    let darkest = inner.iter().min_by(|a, b| a.l.total_cmp(&b.l));
+    let (start, end) = (lch[0], lch[n - 1]);
+    let neutral = [brightest, darkest].into_iter().flatten().any(|mid| {
+        (mid.l - start.l).abs() > 10.0
+            && (mid.l - end.l).abs() > 10.0
+            && (mid.l - start.l).signum() == (mid.l - end.l).signum()
+            && mid.c < start.c.min(end.c)
+    });
+    if neutral {
+        ColormapKind::Diverging
+    } else {
+        ColormapKind::Sequential
+    }
+}
+
+/// Reads a raw ImageJ LUT: 256 reds, greens and blues, optionally preceded by
+/// a 32 byte `ICOL` header.
+fn binary_lut(data: &[u8], name: &str) -> Option<Colormap> {
+    let data = match data.len() {
+        768 => data,
+        800 if data.starts_with(b"ICOL") => &data[32..],
+        _ => return None,
+    };
+    let colors: Vec<RGB> = (0..256)
+        .map(|i| RGB::new(data[i], data[256 + i], data[512 + i]))
+        .collect();
+    let kind = infer_kind(&colors);
+    Some(Colormap::new(name, kind, colors))
+}
+
+fn unit_channel(v: f64, scale: f64) -> u8 {
+    (v / scale * 255.0).clamp(0.0, 255.0).round() as u8
+}
+
+/// Reads rows of `r g b` or `x r g b`, separated by commas, semicolons or
+/// whitespace. Header and comment lines are skipped; values above 1 switch
+/// the whole table to a 0–255 scale. The `x` column, which may also be an
+/// index as in ImageJ's `Index Red Green Blue` text tables, is rescaled to
+/// `[0, 1]`. Rows without a position are spaced evenly.
+fn table(text: &str) -> Result<Vec<(f64, RGB)>, ImportError> {
+    let mut rows: Vec<(usize, Vec<f64>)> = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with("//") {
+            continue;
+        }
+        let fields: Vec<&str> = line
+            .split(|c: char| c == ',' || c == ';' || c.is_whitespace())
+            .filter(|f| !f.is_empty())
+            .collect();
+        let values: Result<Vec<f64>, _> = fields.iter().map(|f| f.parse::<f64>()).collect();
+        match values {
+            Ok(values) if values.len() == 3 || values.len() == 4 => rows.push((i + 1, values)),
+            Ok(values) => return Err(ImportError::parse(i + 1,
+                format!("Expected 3 or 4 columns, found {}", values.len()))),
+            // Allow a single header row before the data
+            Err(_) if rows.is_empty() => continue,
+            Err(_) => return Err(ImportError::parse(i + 1, "Invalid number")),
+        }
+    }
+
+    let max = rows.iter()
+        .flat_map(|(_, v)| v[v.len() - 3..].iter().copied())
+        .fold(0.0, f64::max);
+    let scale = if max > 1.0 { 255.0 } else { 1.0 };
+    let last = rows.len().saturating_sub(1).max(1) as f64;
+
+    let (lo, hi) = rows.iter()
+        .filter(|(_, v)| v.len() == 4)
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), (_, v)| (lo.min(v[0]), hi.max(v[0])));
+    let span = if hi > lo { hi - lo } else { 1.0 };
+
+    Ok(rows.iter().enumerate().map(|(i, (_, v))| {
+        let (x, rgb) = match v[..] {
+            [x, r, g, b] => ((x - lo) / span, [r, g, b]),
+            [r, g, b] => (i as f64 / last, [r, g, b]),
+            _ => unreachable!(),
+        };
+        let [r, g, b] = rgb.map(|c| unit_channel(c, scale));
+        (x, RGB::new(r, g, b))
+    }).collect())
+}
+
+/// Parses one CPT color, which may span several whitespace separated fields.
+/// Returns the color and the number of fields consumed.
+fn cpt_color(fields: &[&str], hsv: bool) -> Option<(RGB, usize)> {
+    let first = *fields.first()?;
+    if hsv && first.split('-').count() == 3 {
+        let parts: Vec<f64> = first.split('-').map(|p| p.parse().ok()).collect::<Option<_>>()?;
+        return Some((HSV::new(parts[0], parts[1] * 100.0, parts[2] * 100.0).to_rgb(), 1));
+    }
+    if first.contains('/') {
+        let parts: Vec<u8> = first.split('/').map(|p| p.parse().ok()).collect::<Option<_>>()?;
+        return match parts[..] {
+            [r, g, b] => Some((RGB::new(r, g, b), 1)),
+            [gray] => Some((RGB::new(gray, gray, gray), 1)),
+            _ => None,
+        };
+    }
+    if let [r, g, b, ..] = fields {
+        if let (Ok(r), Ok(g), Ok(b)) = (r.parse(), g.parse(), b.parse()) {
+            return Some((RGB::new(r, g, b), 3));
+        }
+    }
+    if let Ok(gray) = first.parse::<u8>() {
+        return Some((RGB::new(gray, gray, gray), 1));
+    }
+    first.parse::<RGB>().ok().map(|rgb| (rgb, 1))
+}
+
+/// Reads a GMT color palette table, normalizing the z range to `[0, 1]`.
+fn cpt(text: &str) -> Result<Vec<(f64, RGB)>, ImportError> {
+    let mut hsv = false;
+    let mut stops = Vec::new();
+
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if let Some(comment) = line.strip_prefix('#') {
+            if comment.replace(' ', "").eq_ignore_ascii_case("COLOR_MODEL=HSV") {
+                hsv = true;
+            }
+            continue;
+        }
+        // Drop the optional annotation after ';'
+        let line = line.split(';').next().unwrap_or("").trim();
+        if line.is_empty() || line.starts_with(['B', 'F', 'N']) {
+            continue;
+        }
+
+        let error = || ImportError::parse(i + 1, "Expected 'z0 color z1 color'");
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let z0: f64 = fields[0].parse().map_err(|_| error())?;
+        let (c0, used) = cpt_color(&fields[1..], hsv).ok_or_else(error)?;
+        let rest = &fields[1 + used..];
+        let z1: f64 = rest.first().and_then(|z| z.parse().ok()).ok_or_else(error)?;
+        let (c1, _) = cpt_color(&rest[1..], hsv).ok_or_else(error)?;
+        stops.push((z0, c0));
+        stops.push((z1, c1));
+    }
+
+    let (lo, hi) = stops.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), (z, _)| {
+        (lo.min(*z), hi.max(*z))
+    });
+    let span = if hi > lo { hi - lo } else { 1.0 };
+    Ok(stops.into_iter().map(|(z, c)| ((z - lo) / span, c)).collect())
+}
+
+/// Decodes the JSON string starting just after its opening quote, or returns
+/// `None` if it is unterminated or has an invalid escape.
+fn json_string(s: &str) -> Option<String> {
+    let mut out = String::new();
+    let mut chars = s.chars();
+    loop {
+        match chars.next()? {
+            '"' => return Some(out),
+            '\\' => match chars.next()? {
+                'n' => out.push('\n'),
+                'r' => out.push('\r'),
+                't' => out.push('\t'),
+                'b' => out.push('\u{8}'),
+                'f' => out.push('\u{c}'),
+                'u' => {
+                    let hex: String = chars.by_ref().take(4).collect();
+                    out.push(char::from_u32(u32::from_str_radix(&hex, 16).ok()?)?);
+                },
+                c @ ('"' | '\\' | '/') => out.push(c),
+                _ => return None,
+            },
+            c => out.push(c),
+        }
+    }
+}
+
+/// Extracts the `Name` and `RGBPoints` of the first preset in a ParaView JSON
+/// file. Positions are normalized to `[0, 1]`.
+fn paraview_json(text: &str, default_name: &str) -> Result<(String, Vec<(f64, RGB)>), ImportError> {
+    let name = text.find("\"Name\"")
+        .and_then(|i| {
+            let rest = text[i + 6..].trim_start().strip_prefix(':')?.trim_start();
+            json_string(rest.strip_prefix('"')?)
+        })
+        .unwrap_or_else(|| default_name.to_string());
+
+    let missing = || ImportError::parse(0, "No \"RGBPoints\" array found");
+    let start = text.find("\"RGBPoints\"").ok_or_else(missing)?;
+    let open = start + text[start..].find('[').ok_or_else(missing)?;
+    let close = open + text[open..].find(']').ok_or_else(missing)?;
+    let numbers: Vec<f64> = text[open + 1..close]
+        .split(',')
+        .map(|n| n.trim().parse())
+        .collect::<Result<_, _>>()
+        .map_err(|_| ImportError::parse(0, "Invalid number in \"RGBPoints\""))?;
+    if !numbers.len().is_multiple_of(4) {
+        return Err(ImportError::parse(0, "\"RGBPoints\" length is not a multiple of 4"));
+    }
+
+    let (lo, hi) = numbers.chunks(4).fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), p| {
+        (lo.min(p[0]), hi.max(p[0]))
+    });
+    let span = if hi > lo { hi - lo } else { 1.0 };
+    let stops = numbers.chunks(4)
+        .map(|p| {
+            let rgb = RGB::new(unit_channel(p[1], 1.0), unit_channel(p[2], 1.0), unit_channel(p[3], 1.0));
+            ((p[0] - lo) / span, rgb)
+        })
+        .collect();
+    Ok((name, stops))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::colormap::export::ExportFormat;
+
+    fn import(text: &str, format: ImportFormat) -> Colormap {
+        Colormap::import(text.as_bytes(), format, "test").unwrap()
+    }
+
+    fn ends(colormap: &Colormap) -> (RGB, RGB) {
+        (colormap.colors[0], colormap.colors[colormap.colors.len() - 1])
+    }
+
+    /// Resampling to 256 colors moves stops slightly off the sample grid.
+    fn assert_near(actual: RGB, expected: RGB) {
+        let close = |a: u8, b: u8| a.abs_diff(b) <= 2;
+        assert!(close(actual.r, expected.r) && close(actual.g, expected.g) && close(actual.b, expected.b),
+            "{:?} is not near {:?}", actual, expected);
+    }
+
+    #[test]
+    fn cpt_normalizes_z_range() {
+        let text = "# COLOR_MODEL = RGB\n\
+                    -10 0 0 255 0 255 255 255 ; low\n\
+                    0 255/255/255 10 red\n\
+                    B 0 0 0\n";
+        let colormap = import(text, ImportFormat::Cpt);
+        assert_eq!(ends(&colormap), (RGB::new(0, 0, 255), RGB::new(255, 0, 0)));
+        assert_near(colormap.sample(0.5), RGB::new(255, 255, 255));
+    }
+
+    #[test]
+    fn cpt_reads_hsv() {
+        let text = "# COLOR_MODEL = HSV\n0 0-1-1 1 240-1-1\n";
+        let colormap = import(text, ImportFormat::Cpt);
+        assert_eq!(ends(&colormap), (RGB::new(255, 0, 0), RGB::new(0, 0, 255)));
+    }
+
+    #[test]
+    fn paraview_json_reads_name_and_points() {
+        let text = r#"[{"Name": "Blue to Red", "RGBPoints": [-1, 0, 0, 1, 0, 1, 1, 1, 3, 1, 0, 0]}]"#;
+        let colormap = import(text, ImportFormat::ParaviewJson);
+        assert_eq!(colormap.name, "Blue to Red");
+        assert_eq!(ends(&colormap), (RGB::new(0, 0, 255), RGB::new(255, 0, 0)));
+        assert_near(colormap.sample(0.25), RGB::new(255, 255, 255));
+    }
+
+    #[test]
+    fn paraview_json_requires_points() {
+        let error = Colormap::import(br#"{"Name": "x"}"#, ImportFormat::ParaviewJson, "x").unwrap_err();
+        assert_eq!(error.to_string(), "No \"RGBPoints\" array found");
+    }
+
+    #[test]
+    fn csv_spaces_rows_evenly() {
+        let colormap = import("r,g,b\n0,0,0\n1,1,1\n0,0,1\n", ImportFormat::Csv);
+        assert_eq!(ends(&colormap), (RGB::new(0, 0, 0), RGB::new(0, 0, 255)));
+        assert_near(colormap.sample(0.5), RGB::new(255, 255, 255));
+    }
+
+    #[test]
+    fn csv_rescales_position_column() {
+        let text = "x,r,g,b\n0,0,0,0\n10,1,1,1\n40,1,0,0\n";
+        let colormap = import(text, ImportFormat::Csv);
+        assert_eq!(ends(&colormap), (RGB::new(0, 0, 0), RGB::new(255, 0, 0)));
+        assert_near(colormap.sample(0.25), RGB::new(255, 255, 255));
+    }
+
+    #[test]
+    fn csv_reports_bad_rows() {
+        let error = Colormap::import(b"0 0 0\n1 1\n", ImportFormat::Csv, "x").unwrap_err();
+        assert_eq!(error.to_string(), "Line 2: Expected 3 or 4 columns, found 2");
+    }
+
+    #[test]
+    fn imagej_text_lut() {
+        let mut text = String::from("Index\tRed\tGreen\tBlue\n");
+        for i in 0..256 {
+            text.push_str(&format!("{}\t{}\t{}\t{}\n", i, i, 255 - i, 128));
+        }
+        let colormap = import(&text, ImportFormat::ImageJLut);
+        assert_eq!(ends(&colormap), (RGB::new(0, 255, 128), RGB::new(255, 0, 128)));
+    }
+
+    #[test]
+    fn imagej_binary_lut() {
+        let mut data: Vec<u8> = b"ICOL".to_vec();
+        data.resize(32, 0);
+        data.extend(0..=255u8);
+        data.extend((0..=255u8).rev());
+        data.extend([7u8; 256]);
+        let colormap = Colormap::import(&data, ImportFormat::ImageJLut, "binary").unwrap();
+        assert_eq!(colormap.colors.len(), 256);
+        assert_eq!(ends(&colormap), (RGB::new(0, 255, 7), RGB::new(255, 0, 7)));
+    }
+
+    #[test]
+    fn infers_kind_of_exported_builtins() {
+        for name in ["viridis", "batlow", "vik", "roma"] {
+            let builtin = Colormap::builtin(name).unwrap();
+            let cpt = builtin.export(ExportFormat::Gmt, 64);
+            assert_eq!(import(&cpt, ImportFormat::Cpt).kind, builtin.kind, "{}", name);
+        }
+        let ring = Colormap::new("ring", ColormapKind::Cyclic,
+            vec![RGB::new(255, 0, 0), RGB::new(0, 255, 0), RGB::new(0, 0, 255), RGB::new(255, 0, 0)]);
+        let json = ring.export(ExportFormat::ParaviewJson, 16);
+        assert_eq!(import(&json, ImportFormat::ParaviewJson).kind, ColormapKind::Cyclic);
+    }
+}
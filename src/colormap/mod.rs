@@ -0,0 +1,325 @@
+//! Colormaps for mapping scalar data to colors.
+//!
+//! Built-in maps include the matplotlib perceptually uniform set (viridis,
+//! magma, inferno, plasma, cividis), turbo, and a selection of Fabio
+//! Crameri's scientific colormaps (batlow, roma, vik).
+
+mod analyze;
+mod data;
+pub(crate) mod export;
+mod generate;
+mod import;
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::linear::srgb_to_linear;
+use crate::xyz::lerp;
+use crate::{Cam16Ucs, ContrastMethod, LinearRGB, Oklab, RGB};
+
+pub use analyze::{UniformityIssue, UniformityReport};
+pub use export::ExportFormat;
+pub use generate::{Neutral, FOUR_PHASE};
+pub use import::{ImportError, ImportFormat};
+
+/// The intended use of a colormap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColormapKind {
+    /// Ordered data increasing from low to high.
+    Sequential,
+    /// Data that deviates in both directions from a meaningful midpoint.
+    Diverging,
+    /// Periodic data, such as phase or direction, whose ends meet.
+    Cyclic,
+    /// A hue-cycling map tuned for smooth appearance rather than uniformity.
+    Rainbow,
+}
+
+impl fmt::Display for ColormapKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            ColormapKind::Sequential => "sequential",
+            ColormapKind::Diverging => "diverging",
+            ColormapKind::Cyclic => "cyclic",
+            ColormapKind::Rainbow => "rainbow",
+        };
+        f.pad(name)
+    }
+}
+
+impl FromStr for ColormapKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "sequential" => Ok(ColormapKind::Sequential),
+            "diverging" => Ok(ColormapKind::Diverging),
+            "cyclic" => Ok(ColormapKind::Cyclic),
+            "rainbow" => Ok(ColormapKind::Rainbow),
+            _ => Err(format!("Unknown colormap kind '{}'", s)),
+        }
+    }
+}
+
+/// The color space in which a colormap interpolates between control colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InterpolationSpace {
+    /// Gamma-encoded sRGB, as used by matplotlib and most plotting libraries.
+    #[default]
+    Srgb,
+    /// Linear-light sRGB.
+    LinearRgb,
+    /// Oklab, for perceptually even transitions.
+    Oklab,
+    /// CAM16-UCS, a perceptual space based on a full color appearance model.
+    Cam16Ucs,
+}
+
+impl InterpolationSpace {
+    /// Blends `a` toward `b` by the fraction `f` in `[0, 1]`.
+    pub fn mix(self, a: RGB, b: RGB, f: f64) -> RGB {
+        match self {
+            InterpolationSpace::Srgb => {
+                let [r, g, b] = lerp(unit(a), unit(b), f).map(|c| c.round() as u8);
+                RGB { r, g, b }
+            },
+            _ => self.mix_linear(a, b, f).to_rgb(),
+        }
+    }
+
+    /// Like [`mix`](Self::mix), but returns the result in linear light
+    /// without quantizing it to 8 bits.
+    pub fn mix_linear(self, a: RGB, b: RGB, f: f64) -> LinearRGB {
+        match self {
+            InterpolationSpace::Srgb => {
+                let [r, g, b] = lerp(unit(a), unit(b), f).map(|c| srgb_to_linear(c / 255.0));
+                LinearRGB::new(r, g, b)
+            },
+            InterpolationSpace::LinearRgb => {
+                let linear = |c: RGB| {
+                    let c = c.to_linear();
+                    [c.r, c.g, c.b]
+                };
+                let [r, g, b] = lerp(linear(a), linear(b), f);
+                LinearRGB::new(r, g, b)
+            },
+            InterpolationSpace::Oklab => {
+                let oklab = |c: RGB| {
+                    let c = c.to_oklab();
+                    [c.l, c.a, c.b]
+                };
+                let [l, a, b] = lerp(oklab(a), oklab(b), f);
+                Oklab::new(l, a, b).to_linear()
+            },
+            InterpolationSpace::Cam16Ucs => {
+                let ucs = |c: RGB| {
+                    let c = c.to_cam16_ucs();
+                    [c.j, c.a, c.b]
+                };
+                let [j, a, b] = lerp(ucs(a), ucs(b), f);
+                Cam16Ucs::new(j, a, b).to_linear()
+            },
+        }
+    }
+}
+
+/// The 8-bit channels of `rgb` as floats.
+fn unit(rgb: RGB) -> [f64; 3] {
+    [rgb.r as f64, rgb.g as f64, rgb.b as f64]
+}
+
+impl FromStr for InterpolationSpace {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "srgb" | "rgb" => Ok(InterpolationSpace::Srgb),
+            "linear" | "linear-rgb" => Ok(InterpolationSpace::LinearRgb),
+            "oklab" => Ok(InterpolationSpace::Oklab),
+            "cam16" | "cam16-ucs" => Ok(InterpolationSpace::Cam16Ucs),
+            _ => Err(format!("Unknown interpolation space '{}'", s)),
+        }
+    }
+}
+
+/// A colormap stored as evenly spaced control colors.
+///
+/// Sampling interpolates linearly between neighboring control colors in the
+/// configured [`InterpolationSpace`]. Reversal and truncation are recorded as
+/// a sub-range of the control colors, so they never lose precision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Colormap {
+    pub name: String,
+    pub kind: ColormapKind,
+    pub colors: Vec<RGB>,
+    pub interpolation: InterpolationSpace,
+    /// Positions along `colors` that `t = 0` and `t = 1` map to.
+    range: (f64, f64),
+}
+
+impl Colormap {
+    /// Creates a colormap from its evenly spaced control colors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `colors` is empty.
+    pub fn new(name: impl Into<String>, kind: ColormapKind, colors: Vec<RGB>) -> Self {
+        assert!(!colors.is_empty(), "a colormap needs at least one color");
+        Colormap {
+            name: name.into(),
+            kind,
+            colors,
+            interpolation: InterpolationSpace::default(),
+            range: (0.0, 1.0),
+        }
+    }
+
+    /// Creates a colormap of `n` evenly spaced colors from stops at arbitrary
+    /// positions in `[0, 1]`, interpolating linearly in sRGB between them.
+    ///
+    /// Returns `None` if there are no stops.
+    pub fn from_stops(
+        name: impl Into<String>,
+        kind: ColormapKind,
+        stops: &[(f64, RGB)],
+        n: usize,
+    ) -> Option<Colormap> {
+        let mut stops = stops.to_vec();
+        stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let (first, last) = (*stops.first()?, *stops.last()?);
+
+        let colors = (0..n.max(2))
+            .map(|i| {
+                let x = i as f64 / (n.max(2) - 1) as f64;
+                if x <= first.0 {
+                    return first.1;
+                }
+                match stops.windows(2).find(|w| x <= w[1].0) {
+                    Some(w) if w[1].0 > w[0].0 => {
+                        let f = (x - w[0].0) / (w[1].0 - w[0].0);
+                        InterpolationSpace::Srgb.mix(w[0].1, w[1].1, f)
+                    },
+                    Some(w) => w[1].1,
+                    None => last.1,
+                }
+            })
+            .collect();
+        Some(Colormap::new(name, kind, colors))
+    }
+
+    /// Returns the colormap interpolating in the given space.
+    pub fn with_interpolation(mut self, interpolation: InterpolationSpace) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// Returns the colormap running in the opposite direction, toggling the
+    /// matplotlib-style `_r` suffix on its name.
+    pub fn reversed(mut self) -> Self {
+        self.range = (self.range.1, self.range.0);
+        self.name = match self.name.strip_suffix("_r") {
+            Some(name) => name.to_string(),
+            None => format!("{}_r", self.name),
+        };
+        self
+    }
+
+    /// Returns the part of the colormap between `start` and `end`, both in
+    /// `[0, 1]`, stretched over the full `[0, 1]` input range.
+    pub fn truncated(mut self, start: f64, end: f64) -> Self {
+        let (a, b) = self.range;
+        let at = |t: f64| a + (b - a) * t.clamp(0.0, 1.0);
+        self.range = (at(start), at(end));
+        self
+    }
+
+    /// Returns the color at position `t` in `[0, 1]`; values outside the
+    /// range are clamped.
+    pub fn sample(&self, t: f64) -> RGB {
+        match self.locate(t) {
+            Some((i, f)) => self.interpolation.mix(self.colors[i], self.colors[i + 1], f),
+            None => self.colors[0],
+        }
+    }
+
+    /// Returns the color at position `t` in linear light, without the 8-bit
+    /// quantization of [`sample`](Self::sample).
+    pub fn sample_linear(&self, t: f64) -> LinearRGB {
+        match self.locate(t) {
+            Some((i, f)) => self.interpolation.mix_linear(self.colors[i], self.colors[i + 1], f),
+            None => self.colors[0].to_linear(),
+        }
+    }
+
+    /// Finds the control colors around position `t`, returning the index of
+    /// the first and how far `t` lies toward the next, or `None` if there is
+    /// only one control color.
+    fn locate(&self, t: f64) -> Option<(usize, f64)> {
+        let n = self.colors.len();
+        if n == 1 {
+            return None;
+        }
+
+        let (a, b) = self.range;
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        let x = (a + (b - a) * t) * (n - 1) as f64;
+        let i = (x.floor() as usize).min(n - 2);
+        Some((i, x - i as f64))
+    }
+
+    /// Returns `n` evenly spaced samples covering the full range, including
+    /// both ends.
+    pub fn discretize(&self, n: usize) -> Vec<RGB> {
+        match n {
+            0 => Vec::new(),
+            1 => vec![self.sample(0.5)],
+            _ => (0..n).map(|i| self.sample(i as f64 / (n - 1) as f64)).collect(),
+        }
+    }
+
+    /// Returns the built-in colormap with the given (case-insensitive) name.
+    pub fn builtin(name: &str) -> Option<Colormap> {
+        data::BUILTINS.iter()
+            .find(|map| map.name.eq_ignore_ascii_case(name.trim()))
+            .map(|map| Colormap::new(map.name, map.kind, map.colors.to_vec()))
+    }
+
+    /// Returns the names of all built-in colormaps.
+    pub fn builtin_names() -> impl Iterator<Item = &'static str> {
+        data::BUILTINS.iter().map(|map| map.name)
+    }
+
+    /// Returns all built-in colormaps.
+    pub fn builtins() -> impl Iterator<Item = Colormap> {
+        data::BUILTINS.iter()
+            .map(|map| Colormap::new(map.name, map.kind, map.colors.to_vec()))
+    }
+
+    /// Returns the most legible text color from `candidates` for each of
+    /// `n` evenly spaced samples, as from [`discretize`](Self::discretize).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `candidates` is empty.
+    pub fn text_colors(&self, n: usize, candidates: &[RGB], method: ContrastMethod) -> Vec<RGB> {
+        self.discretize(n).into_iter()
+            .map(|color| color.best_text_color(candidates, method).expect("no text color candidates"))
+            .collect()
+    }
+
+    /// Renders the colormap as a row of `width` one-cell truecolor blocks.
+    pub fn preview(&self, width: usize) -> String {
+        self.discretize(width).iter()
+            .map(|color| color.to_ansi_color_cells(1))
+            .collect()
+    }
+}
+
+impl FromStr for Colormap {
+    type Err = String;
+
+    /// Looks up a built-in colormap by name.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Colormap::builtin(s).ok_or_else(|| format!("Unknown colormap '{}'", s.trim()))
+    }
+}
@@ -0,0 +1,191 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::RGB;
+
+/// A WCAG 2.x conformance level for text contrast.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WcagLevel {
+    #[default]
+    AA,
+    AAA,
+}
+
+impl WcagLevel {
+    /// The minimum contrast ratio for normal or large text at this level.
+    pub fn min_ratio(self, large_text: bool) -> f64 {
+        match (self, large_text) {
+            (WcagLevel::AA, false) => 4.5,
+            (WcagLevel::AA, true) => 3.0,
+            (WcagLevel::AAA, false) => 7.0,
+            (WcagLevel::AAA, true) => 4.5,
+        }
+    }
+}
+
+impl fmt::Display for WcagLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(match self {
+            WcagLevel::AA => "AA",
+            WcagLevel::AAA => "AAA",
+        })
+    }
+}
+
+impl FromStr for WcagLevel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "aa" => Ok(WcagLevel::AA),
+            "aaa" => Ok(WcagLevel::AAA),
+            _ => Err(format!("Unknown WCAG level '{}'", s)),
+        }
+    }
+}
+
+/// The measure used to choose a legible text color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ContrastMethod {
+    /// WCAG 2.x contrast ratio.
+    #[default]
+    Wcag,
+    /// APCA lightness contrast, by magnitude of Lc.
+    Apca,
+}
+
+impl ContrastMethod {
+    /// Returns the contrast of `text` on `background`, larger meaning more
+    /// legible.
+    pub fn contrast(self, text: RGB, background: RGB) -> f64 {
+        match self {
+            ContrastMethod::Wcag => text.contrast_ratio(background),
+            ContrastMethod::Apca => text.apca_contrast(background).abs(),
+        }
+    }
+}
+
+impl FromStr for ContrastMethod {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "wcag" | "wcag2" => Ok(ContrastMethod::Wcag),
+            "apca" => Ok(ContrastMethod::Apca),
+            _ => Err(format!("Unknown contrast method '{}'", s)),
+        }
+    }
+}
+
+/// APCA screen luminance, with the soft clamp for near-black colors.
+fn apca_luminance(rgb: RGB) -> f64 {
+    let channel = |c: u8| (c as f64 / 255.0).powf(2.4);
+    let y = 0.2126729 * channel(rgb.r) + 0.7151522 * channel(rgb.g) + 0.0721750 * channel(rgb.b);
+    if y < 0.022 {
+        y + (0.022 - y).powf(1.414)
+    } else {
+        y
+    }
+}
+
+impl RGB {
+    /// WCAG 2.x relative luminance, in `[0, 1]`.
+    pub fn relative_luminance(self) -> f64 {
+        let linear = self.to_linear();
+        0.2126 * linear.r + 0.7152 * linear.g + 0.0722 * linear.b
+    }
+
+    /// WCAG 2.x contrast ratio against `other`, from 1 to 21. The order of
+    /// the two colors does not matter.
+    pub fn contrast_ratio(self, other: RGB) -> f64 {
+        let (a, b) = (self.relative_luminance(), other.relative_luminance());
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// APCA lightness contrast Lc of this color as text on `background`
+    /// (APCA 0.0.98G).
+    ///
+    /// Positive values mean dark text on a light background and negative
+    /// values light text on a dark background; the magnitude runs from 0 to
+    /// about 106 for black on white.
+    pub fn apca_contrast(self, background: RGB) -> f64 {
+        let (text, bg) = (apca_luminance(self), apca_luminance(background));
+        if (bg - text).abs() < 0.0005 {
+            return 0.0;
+        }
+
+        let contrast = if bg > text {
+            let sapc = (bg.powf(0.56) - text.powf(0.57)) * 1.14;
+            if sapc < 0.1 { 0.0 } else { sapc - 0.027 }
+        } else {
+            let sapc = (bg.powf(0.65) - text.powf(0.62)) * 1.14;
+            if sapc > -0.1 { 0.0 } else { sapc + 0.027 }
+        };
+        contrast * 100.0
+    }
+
+    /// Returns the candidate that is most legible as text on this
+    /// background, or `None` if there are no candidates.
+    pub fn best_text_color(self, candidates: &[RGB], method: ContrastMethod) -> Option<RGB> {
+        candidates.iter()
+            .copied()
+            .max_by(|a, b| method.contrast(*a, self).total_cmp(&method.contrast(*b, self)))
+    }
+
+    /// Returns black or white, whichever is more legible as text on this
+    /// background.
+    pub fn text_color(self, method: ContrastMethod) -> RGB {
+        let black_and_white = [RGB::new(0, 0, 0), RGB::new(255, 255, 255)];
+        self.best_text_color(&black_and_white, method)
+            .expect("there are two candidates")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BLACK: RGB = RGB::new(0, 0, 0);
+    const WHITE: RGB = RGB::new(255, 255, 255);
+
+    fn hex(s: &str) -> RGB {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn apca_matches_reference_values() {
+        // From the APCA 0.0.98G reference implementation
+        let cases = [
+            (BLACK, WHITE, 106.04067),
+            (WHITE, BLACK, -107.88473),
+            (hex("#888888"), WHITE, 63.05647),
+            (WHITE, hex("#888888"), -68.54146),
+            (BLACK, hex("#aaaaaa"), 58.14626),
+            (hex("#aaaaaa"), BLACK, -56.24113),
+        ];
+        for (text, background, expected) in cases {
+            let actual = text.apca_contrast(background);
+            assert!((actual - expected).abs() < 1e-4, "{} on {}: {} != {}",
+                text.to_hex(), background.to_hex(), actual, expected);
+        }
+        assert_eq!(hex("#777777").apca_contrast(hex("#777777")), 0.0);
+    }
+
+    #[test]
+    fn wcag_ratio_is_symmetric_and_bounded() {
+        assert!((BLACK.contrast_ratio(WHITE) - 21.0).abs() < 1e-9);
+        assert_eq!(WHITE.contrast_ratio(BLACK), BLACK.contrast_ratio(WHITE));
+        assert_eq!(WHITE.contrast_ratio(WHITE), 1.0);
+        // #767676 is the lightest gray that passes AA on white
+        assert!(hex("#767676").contrast_ratio(WHITE) >= WcagLevel::AA.min_ratio(false));
+        assert!(hex("#777777").contrast_ratio(WHITE) < WcagLevel::AA.min_ratio(false));
+    }
+
+    #[test]
+    fn text_color_picks_the_more_legible_extreme() {
+        for method in [ContrastMethod::Wcag, ContrastMethod::Apca] {
+            assert_eq!(hex("#ffff00").text_color(method), BLACK);
+            assert_eq!(hex("#000080").text_color(method), WHITE);
+        }
+    }
+}
@@ -0,0 +1,365 @@
+//! Parsing of CSS Color Level 4 functional notations.
+//!
+//! Supports `rgb()`, `rgba()`, `hsl()`, `hsla()`, `hwb()`, `lab()`, `lch()`,
+//! `oklab()`, `oklch()` and `color()` with the `srgb`, `srgb-linear`,
+//! `display-p3`, `xyz`, `xyz-d50` and `xyz-d65` color spaces. Both the legacy
+//! comma separated and the modern space separated syntax are accepted, along
+//! with `none` components, percentages, angle units and `/ alpha`. The
+//! non-standard `hsv()`/`hsb()` notation is accepted as well.
+
+use crate::{LCh, Lab, LinearRGB, Oklab, Oklch, WhitePoint, HSL, HSV, HWB, RGB, XYZ};
+use crate::{ParseColorError, ParseErrorKind};
+
+/// A parsed `name(args / alpha)` expression whose components have not yet
+/// been interpreted.
+pub(crate) struct Function<'a> {
+    /// The complete input, against which error spans are reported.
+    pub source: &'a str,
+    pub name: String,
+    pub name_span: &'a str,
+    pub inner: &'a str,
+    pub args: Vec<&'a str>,
+    pub alpha: Option<&'a str>,
+}
+
+impl<'a> Function<'a> {
+    /// Splits a functional notation into its name, components and alpha.
+    pub fn parse(source: &'a str) -> Result<Self, ParseColorError> {
+        let syntax = |reason: String, part: &str| {
+            ParseColorError::at(ParseErrorKind::UnsupportedSyntax(reason), source, part)
+        };
+
+        let s = source.trim();
+        let open = s.find('(').ok_or_else(|| {
+            syntax("Invalid color function. Expected name(...)".to_string(), s)
+        })?;
+        let name = s[..open].trim();
+        if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+            return Err(syntax(format!("Invalid color function name '{}'", name), &s[..open]));
+        }
+        let inner = s[open + 1..].strip_suffix(')').ok_or_else(|| {
+            syntax(format!("Missing closing parenthesis in {}()", name), &s[s.len()..])
+        })?;
+
+        let (args, alpha) = if inner.contains(',') {
+            // Legacy syntax: comma separated, alpha as an optional fourth value
+            let mut parts: Vec<&str> = inner.split(',').map(str::trim).collect();
+            if let Some(part) = parts.iter().find(|p| p.is_empty() || p.contains(char::is_whitespace)) {
+                return Err(syntax(format!("Invalid component list in {}()", name), part));
+            }
+            let alpha = if parts.len() == 4 { parts.pop() } else { None };
+            (parts, alpha)
+        } else {
+            let (components, alpha) = match inner.split_once('/') {
+                Some((components, alpha)) => {
+                    let alpha = alpha.trim();
+                    if alpha.is_empty() || alpha.contains(char::is_whitespace) {
+                        return Err(syntax(format!("Invalid alpha in {}()", name), alpha));
+                    }
+                    (components, Some(alpha))
+                },
+                None => (inner, None),
+            };
+            (components.split_whitespace().collect(), alpha)
+        };
+
+        Ok(Function {
+            source,
+            name: name.to_ascii_lowercase(),
+            name_span: name,
+            inner,
+            args,
+            alpha,
+        })
+    }
+
+    fn error(&self, kind: ParseErrorKind, part: &str) -> ParseColorError {
+        ParseColorError::at(kind, self.source, part)
+    }
+
+    fn number(
+        &self,
+        s: &str,
+        percent_ref: f64,
+        component: &'static str,
+    ) -> Result<f64, ParseColorError> {
+        number(self.source, s, percent_ref, component)
+    }
+
+    fn hue(&self, s: &str) -> Result<f64, ParseColorError> {
+        hue(s).ok_or_else(|| self.error(ParseErrorKind::InvalidComponent("hue"), s))
+    }
+
+    /// Returns the components, checking the function name and arity.
+    fn expect<const N: usize>(
+        &self,
+        names: &[&str],
+        expected: &'static str,
+    ) -> Result<[&'a str; N], ParseColorError> {
+        if !names.contains(&self.name.as_str()) {
+            let reason = format!("Expected {}(), found {}()", names[0], self.name);
+            return Err(self.error(ParseErrorKind::UnsupportedSyntax(reason), self.name_span));
+        }
+        self.args.clone().try_into().map_err(|_| {
+            let found = self.args.len();
+            self.error(ParseErrorKind::InvalidLength { expected, found }, self.inner)
+        })
+    }
+
+    /// Returns the alpha component in `[0, 1]`, defaulting to opaque.
+    pub fn alpha(&self) -> Result<f64, ParseColorError> {
+        match self.alpha {
+            Some(alpha) => Ok(self.number(alpha, 1.0, "alpha")?.clamp(0.0, 1.0)),
+            None => Ok(1.0),
+        }
+    }
+
+    pub fn rgb(&self) -> Result<RGB, ParseColorError> {
+        let [r, g, b] = self.expect(&["rgb", "rgba"], "3 components")?;
+        let channel = |s, what| {
+            self.number(s, 255.0, what).map(|v| v.clamp(0.0, 255.0).round() as u8)
+        };
+        Ok(RGB {
+            r: channel(r, "red")?,
+            g: channel(g, "green")?,
+            b: channel(b, "blue")?,
+        })
+    }
+
+    pub fn hsl(&self) -> Result<HSL, ParseColorError> {
+        let [h, s, l] = self.expect(&["hsl", "hsla"], "3 components")?;
+        Ok(HSL {
+            h: self.hue(h)?,
+            s: self.number(s, 100.0, "saturation")?.clamp(0.0, 100.0),
+            l: self.number(l, 100.0, "lightness")?.clamp(0.0, 100.0),
+        })
+    }
+
+    pub fn hsv(&self) -> Result<HSV, ParseColorError> {
+        let [h, s, v] = self.expect(&["hsv", "hsb"], "3 components")?;
+        Ok(HSV {
+            h: self.hue(h)?,
+            s: self.number(s, 100.0, "saturation")?,
+            v: self.number(v, 100.0, "value")?,
+        })
+    }
+
+    pub fn hwb(&self) -> Result<HWB, ParseColorError> {
+        let [h, w, b] = self.expect(&["hwb"], "3 components")?;
+        Ok(HWB {
+            h: self.hue(h)?,
+            w: self.number(w, 100.0, "whiteness")?.clamp(0.0, 100.0),
+            b: self.number(b, 100.0, "blackness")?.clamp(0.0, 100.0),
+        })
+    }
+
+    pub fn lab(&self) -> Result<Lab, ParseColorError> {
+        let [l, a, b] = self.expect(&["lab"], "3 components")?;
+        Ok(Lab {
+            l: self.number(l, 100.0, "lightness")?.clamp(0.0, 100.0),
+            a: self.number(a, 125.0, "a")?,
+            b: self.number(b, 125.0, "b")?,
+        })
+    }
+
+    pub fn lch(&self) -> Result<LCh, ParseColorError> {
+        let [l, c, h] = self.expect(&["lch"], "3 components")?;
+        Ok(LCh {
+            l: self.number(l, 100.0, "lightness")?.clamp(0.0, 100.0),
+            c: self.number(c, 150.0, "chroma")?.max(0.0),
+            h: self.hue(h)?,
+        })
+    }
+
+    pub fn oklab(&self) -> Result<Oklab, ParseColorError> {
+        let [l, a, b] = self.expect(&["oklab"], "3 components")?;
+        Ok(Oklab {
+            l: self.number(l, 1.0, "lightness")?.clamp(0.0, 1.0),
+            a: self.number(a, 0.4, "a")?,
+            b: self.number(b, 0.4, "b")?,
+        })
+    }
+
+    pub fn oklch(&self) -> Result<Oklch, ParseColorError> {
+        let [l, c, h] = self.expect(&["oklch"], "3 components")?;
+        Ok(Oklch {
+            l: self.number(l, 1.0, "lightness")?.clamp(0.0, 1.0),
+            c: self.number(c, 0.4, "chroma")?.max(0.0),
+            h: self.hue(h)?,
+        })
+    }
+
+    /// Resolves `color(space c1 c2 c3)` to sRGB.
+    fn color(&self) -> Result<RGB, ParseColorError> {
+        let [space, c1, c2, c3] = self.expect(&["color"], "a color space and 3 components")?;
+        let c1 = self.number(c1, 1.0, "first")?;
+        let c2 = self.number(c2, 1.0, "second")?;
+        let c3 = self.number(c3, 1.0, "third")?;
+        let decode = crate::linear::srgb_to_linear;
+
+        let xyz = match space.to_ascii_lowercase().as_str() {
+            "srgb" if [c1, c2, c3].iter().all(|c| (0.0..=1.0).contains(c)) => {
+                let channel = |c: f64| (c * 255.0).round() as u8;
+                return Ok(RGB::new(channel(c1), channel(c2), channel(c3)));
+            },
+            "srgb" => LinearRGB::new(decode(c1), decode(c2), decode(c3)).to_xyz(),
+            "srgb-linear" => LinearRGB::new(c1, c2, c3).to_xyz(),
+            "display-p3" => {
+                // Display P3 shares the sRGB transfer function
+                let (r, g, b) = (decode(c1), decode(c2), decode(c3));
+                XYZ {
+                    x: 0.4865709486 * r + 0.2656676932 * g + 0.1982172852 * b,
+                    y: 0.2289745641 * r + 0.6917385218 * g + 0.0792869141 * b,
+                    z: 0.0451133819 * g + 1.0439443689 * b,
+                }
+            },
+            "xyz" | "xyz-d65" => XYZ::new(c1, c2, c3),
+            "xyz-d50" => XYZ::new(c1, c2, c3).adapt(WhitePoint::D50, WhitePoint::D65),
+            _ => {
+                let reason = format!("Unsupported color space '{}' in color()", space);
+                return Err(self.error(ParseErrorKind::UnsupportedSyntax(reason), space));
+            },
+        };
+        Ok(gamut_map(xyz))
+    }
+
+    /// Resolves any supported function to sRGB, gamut mapping wide-gamut
+    /// colors by reducing Oklch chroma.
+    pub fn to_rgb(&self) -> Result<RGB, ParseColorError> {
+        match self.name.as_str() {
+            "rgb" | "rgba" => self.rgb(),
+            "hsl" | "hsla" => Ok(self.hsl()?.to_rgb()),
+            "hsv" | "hsb" => Ok(self.hsv()?.to_rgb()),
+            "hwb" => Ok(self.hwb()?.to_rgb()),
+            // CSS defines lab() and lch() relative to D50
+            "lab" => Ok(gamut_map(self.lab()?.to_xyz(WhitePoint::D50))),
+            "lch" => Ok(gamut_map(self.lch()?.to_lab().to_xyz(WhitePoint::D50))),
+            "oklab" => Ok(self.oklab()?.to_oklch().to_rgb_in_gamut()),
+            "oklch" => Ok(self.oklch()?.to_rgb_in_gamut()),
+            "color" => self.color(),
+            _ => {
+                let reason = format!("Unsupported color function '{}'", self.name);
+                Err(self.error(ParseErrorKind::UnsupportedSyntax(reason), self.name_span))
+            },
+        }
+    }
+}
+
+fn gamut_map(xyz: XYZ) -> RGB {
+    Oklab::from_linear(xyz.to_linear()).to_oklch().to_rgb_in_gamut()
+}
+
+/// Parses a CSS color function to sRGB plus an alpha value in `[0, 1]`.
+pub fn parse_color(s: &str) -> Result<(RGB, f64), ParseColorError> {
+    let function = Function::parse(s)?;
+    Ok((function.to_rgb()?, function.alpha()?))
+}
+
+/// Parses a number or `none`, scaling percentages so that `100%` equals
+/// `percent_ref`. Errors are reported relative to `source`.
+pub(crate) fn number(
+    source: &str,
+    s: &str,
+    percent_ref: f64,
+    component: &'static str,
+) -> Result<f64, ParseColorError> {
+    if s.eq_ignore_ascii_case("none") {
+        return Ok(0.0);
+    }
+    match s.strip_suffix('%') {
+        Some(pct) => pct.parse::<f64>().map(|v| v * percent_ref / 100.0),
+        None => s.parse(),
+    }
+    .map_err(|_| ParseColorError::at(ParseErrorKind::InvalidComponent(component), source, s))
+}
+
+/// Parses a hue as a bare number of degrees, an angle with a `deg`, `rad`,
+/// `grad` or `turn` unit, or `none`.
+fn hue(s: &str) -> Option<f64> {
+    if s.eq_ignore_ascii_case("none") {
+        return Some(0.0);
+    }
+    let lower = s.to_ascii_lowercase();
+    let (value, scale) = if let Some(v) = lower.strip_suffix("deg") {
+        (v, 1.0)
+    } else if let Some(v) = lower.strip_suffix("grad") {
+        (v, 0.9)
+    } else if let Some(v) = lower.strip_suffix("rad") {
+        (v, 180.0 / std::f64::consts::PI)
+    } else if let Some(v) = lower.strip_suffix("turn") {
+        (v, 360.0)
+    } else {
+        (lower.as_str(), 1.0)
+    };
+    value.parse::<f64>()
+        .ok()
+        .map(|v| (v * scale).rem_euclid(360.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(s: &str) -> String {
+        let (rgb, alpha) = parse_color(s).unwrap_or_else(|e| panic!("{}: {}", s, e));
+        assert_eq!(alpha, 1.0, "{}", s);
+        rgb.to_hex()
+    }
+
+    #[test]
+    fn parses_modern_and_legacy_syntax() {
+        assert_eq!(hex("rgb(255 0 0)"), "#FF0000");
+        assert_eq!(hex("rgb(255, 0, 0)"), "#FF0000");
+        assert_eq!(hex("RGB(100% 50% 0%)"), "#FF8000");
+        assert_eq!(hex("rgb(300 -20 0)"), "#FF0000");
+        assert_eq!(hex("rgb(none 255 none)"), "#00FF00");
+        assert_eq!(hex("hsl(120deg 100% 50%)"), "#00FF00");
+        assert_eq!(hex("hsla(240, 100%, 50%)"), "#0000FF");
+        assert_eq!(hex("hwb(0 0% 0%)"), "#FF0000");
+        assert_eq!(hex("hwb(0 100% 0%)"), "#FFFFFF");
+    }
+
+    #[test]
+    fn parses_alpha() {
+        assert_eq!(parse_color("rgb(255 0 0 / 50%)").unwrap(), (RGB::new(255, 0, 0), 0.5));
+        assert_eq!(parse_color("rgba(255, 0, 0, 0.25)").unwrap(), (RGB::new(255, 0, 0), 0.25));
+        assert_eq!(parse_color("hsl(0 100% 50% / 2)").unwrap().1, 1.0);
+    }
+
+    #[test]
+    fn parses_hue_units() {
+        for s in ["hsl(180 100% 50%)", "hsl(0.5turn 100% 50%)", "hsl(200grad 100% 50%)",
+            "hsl(3.14159rad 100% 50%)", "hsl(-180deg 100% 50%)"] {
+            assert_eq!(hex(s), "#00FFFF", "{}", s);
+        }
+    }
+
+    #[test]
+    fn resolves_lab_and_lch_against_d50() {
+        // The CSS Color 4 specification's sRGB red
+        assert_eq!(hex("lab(54.29 80.8 69.89)"), "#FF0000");
+        assert_eq!(hex("lch(54.29% 106.84 40.86)"), "#FF0000");
+        assert_eq!(hex("lab(100 0 0)"), "#FFFFFF");
+        assert_eq!(hex("lch(0 0 none)"), "#000000");
+    }
+
+    #[test]
+    fn parses_oklab_and_oklch() {
+        assert_eq!(hex("oklab(1 0 0)"), "#FFFFFF");
+        assert_eq!(hex("oklch(62.8% 0.2577 29.23deg)"), "#FF0000");
+        // Out-of-gamut chroma is reduced rather than clipped per channel
+        let wide = parse_color("oklch(0.7 0.4 150)").unwrap().0.to_oklch();
+        assert!((wide.l - 0.7).abs() < 0.01 && (wide.h - 150.0).abs() < 2.0);
+    }
+
+    #[test]
+    fn parses_color_spaces() {
+        assert_eq!(hex("color(srgb 1 0 0)"), "#FF0000");
+        assert_eq!(hex("color(srgb 100% 50% 0%)"), "#FF8000");
+        assert_eq!(hex("color(srgb-linear 0.5 0.5 0.5)"), "#BCBCBC");
+        assert_eq!(hex("color(xyz-d65 0.9505 1 1.089)"), "#FFFFFF");
+        assert_eq!(hex("color(xyz 0 0 0)"), "#000000");
+        assert_eq!(hex("color(xyz-d50 0.9642 1 0.8251)"), "#FFFFFF");
+        assert_eq!(hex("color(display-p3 1 1 1)"), "#FFFFFF");
+    }
+}
@@ -0,0 +1,481 @@
+use std::fmt::{self, Write};
+use std::str::FromStr;
+
+use crate::colormap::export::escape_json;
+use crate::xyz::{lerp, mul};
+use crate::{Colormap, Lab, LinearRGB, Palette, RGB};
+
+/// A type of color vision deficiency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Deficiency {
+    /// Missing or anomalous long-wavelength (red) cones.
+    Protan,
+    /// Missing or anomalous medium-wavelength (green) cones.
+    Deutan,
+    /// Missing or anomalous short-wavelength (blue) cones.
+    Tritan,
+    /// No functioning cones; only luminance is seen.
+    Achromatopsia,
+}
+
+impl Deficiency {
+    /// All deficiency types.
+    pub const ALL: [Deficiency; 4] = [
+        Deficiency::Protan,
+        Deficiency::Deutan,
+        Deficiency::Tritan,
+        Deficiency::Achromatopsia,
+    ];
+}
+
+impl fmt::Display for Deficiency {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(match self {
+            Deficiency::Protan => "protan",
+            Deficiency::Deutan => "deutan",
+            Deficiency::Tritan => "tritan",
+            Deficiency::Achromatopsia => "achromatopsia",
+        })
+    }
+}
+
+impl FromStr for Deficiency {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "protan" | "protanopia" | "protanomaly" => Ok(Deficiency::Protan),
+            "deutan" | "deuteranopia" | "deuteranomaly" => Ok(Deficiency::Deutan),
+            "tritan" | "tritanopia" | "tritanomaly" => Ok(Deficiency::Tritan),
+            "achromatopsia" | "achroma" | "monochromacy" => Ok(Deficiency::Achromatopsia),
+            _ => Err(format!("Unknown color vision deficiency '{}'", s)),
+        }
+    }
+}
+
+/// The model used to simulate a color vision deficiency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CvdModel {
+    /// Machado, Oliveira and Fernandes (2009), which models anomalous
+    /// trichromacy at any severity.
+    #[default]
+    Machado,
+    /// Brettel, Viénot and Mollon (1997), projecting onto two half-planes.
+    Brettel,
+    /// Viénot, Brettel and Mollon (1999), a single-plane simplification of
+    /// Brettel for protan and deutan. Tritan falls back to Brettel, since a
+    /// single plane is a poor fit there.
+    Vienot,
+}
+
+impl fmt::Display for CvdModel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(match self {
+            CvdModel::Machado => "machado",
+            CvdModel::Brettel => "brettel",
+            CvdModel::Vienot => "vienot",
+        })
+    }
+}
+
+impl FromStr for CvdModel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "machado" => Ok(CvdModel::Machado),
+            "brettel" => Ok(CvdModel::Brettel),
+            "vienot" | "viénot" => Ok(CvdModel::Vienot),
+            _ => Err(format!("Unknown CVD model '{}'", s)),
+        }
+    }
+}
+
+type Matrix = [[f64; 3]; 3];
+
+const IDENTITY: Matrix = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+/// Machado et al. (2009) matrices in linear RGB for severities 0.1 to 1.0.
+const MACHADO_PROTAN: [Matrix; 10] = [
+    [[0.856167, 0.182038, -0.038205], [0.029342, 0.955115, 0.015544], [-0.002880, -0.001563, 1.004443]],
+    [[0.734766, 0.334872, -0.069637], [0.051840, 0.919198, 0.028963], [-0.004928, -0.004209, 1.009137]],
+    [[0.630323, 0.465641, -0.095964], [0.069181, 0.890046, 0.040773], [-0.006308, -0.007724, 1.014032]],
+    [[0.539009, 0.579343, -0.118352], [0.082546, 0.866121, 0.051332], [-0.007136, -0.011959, 1.019095]],
+    [[0.458064, 0.679578, -0.137642], [0.092785, 0.846313, 0.060902], [-0.007494, -0.016807, 1.024301]],
+    [[0.385450, 0.769005, -0.154455], [0.100526, 0.829802, 0.069673], [-0.007442, -0.022190, 1.029632]],
+    [[0.319627, 0.849633, -0.169261], [0.106241, 0.815969, 0.077790], [-0.007025, -0.028051, 1.035076]],
+    [[0.259411, 0.923008, -0.182420], [0.110296, 0.804340, 0.085364], [-0.006276, -0.034346, 1.040622]],
+    [[0.203876, 0.990338, -0.194214], [0.112975, 0.794542, 0.092483], [-0.005222, -0.041043, 1.046265]],
+    [[0.152286, 1.052583, -0.204868], [0.114503, 0.786281, 0.099216], [-0.003882, -0.048116, 1.051998]],
+];
+
+const MACHADO_DEUTAN: [Matrix; 10] = [
+    [[0.866435, 0.177704, -0.044139], [0.049567, 0.939063, 0.011370], [-0.003453, 0.007233, 0.996220]],
+    [[0.760729, 0.319078, -0.079807], [0.090568, 0.889315, 0.020117], [-0.006027, 0.013325, 0.992702]],
+    [[0.675425, 0.433850, -0.109275], [0.125303, 0.847755, 0.026942], [-0.007950, 0.018572, 0.989378]],
+    [[0.605511, 0.528560, -0.134071], [0.155318, 0.812366, 0.032316], [-0.009376, 0.023176, 0.986200]],
+    [[0.547494, 0.607765, -0.155259], [0.181692, 0.781742, 0.036566], [-0.010410, 0.027275, 0.983136]],
+    [[0.498864, 0.674741, -0.173604], [0.205199, 0.754872, 0.039929], [-0.011131, 0.030969, 0.980162]],
+    [[0.457771, 0.731899, -0.189670], [0.226409, 0.731012, 0.042579], [-0.011595, 0.034333, 0.977261]],
+    [[0.422823, 0.781057, -0.203881], [0.245752, 0.709602, 0.044646], [-0.011843, 0.037423, 0.974421]],
+    [[0.392952, 0.823610, -0.216562], [0.263559, 0.690210, 0.046232], [-0.011910, 0.040281, 0.971630]],
+    [[0.367322, 0.860646, -0.227968], [0.280085, 0.672501, 0.047413], [-0.011820, 0.042940, 0.968881]],
+];
+
+const MACHADO_TRITAN: [Matrix; 10] = [
+    [[0.926670, 0.092514, -0.019184], [0.021191, 0.964503, 0.014306], [0.008437, 0.054813, 0.936750]],
+    [[0.895720, 0.133330, -0.029050], [0.029997, 0.945400, 0.024603], [0.013027, 0.104707, 0.882266]],
+    [[0.905871, 0.127791, -0.033662], [0.026856, 0.941251, 0.031893], [0.013410, 0.148296, 0.838294]],
+    [[0.948035, 0.089490, -0.037526], [0.014364, 0.946792, 0.038844], [0.010853, 0.193991, 0.795156]],
+    [[1.017277, 0.027029, -0.044306], [-0.006113, 0.958479, 0.047634], [0.006379, 0.248708, 0.744913]],
+    [[1.104996, -0.046633, -0.058363], [-0.032137, 0.971635, 0.060503], [0.001336, 0.317922, 0.680742]],
+    [[1.193214, -0.109812, -0.083402], [-0.058496, 0.979410, 0.079086], [-0.002346, 0.403492, 0.598854]],
+    [[1.257728, -0.139648, -0.118081], [-0.078003, 0.975409, 0.102594], [-0.003316, 0.501214, 0.502102]],
+    [[1.278864, -0.125333, -0.153531], [-0.084748, 0.957674, 0.127074], [-0.000989, 0.601151, 0.399838]],
+    [[1.255528, -0.076749, -0.178779], [-0.078411, 0.930809, 0.147602], [0.004733, 0.691367, 0.303900]],
+];
+
+/// Brettel et al. (1997) projections for sRGB: the matrices for either side
+/// of the separating plane, and the plane's normal, in linear RGB.
+struct Brettel {
+    first: Matrix,
+    normal: [f64; 3],
+    second: Matrix,
+}
+
+const BRETTEL_PROTAN: Brettel = Brettel {
+    first: [[0.14510, 1.20165, -0.34675], [0.10447, 0.85316, 0.04237], [0.00429, -0.00603, 1.00174]],
+    normal: [0.00048, 0.00416, -0.00464],
+    second: [[0.14115, 1.16782, -0.30897], [0.10495, 0.85730, 0.03776], [0.00431, -0.00586, 1.00155]],
+};
+
+const BRETTEL_DEUTAN: Brettel = Brettel {
+    first: [[0.36198, 0.86755, -0.22953], [0.26099, 0.64512, 0.09389], [-0.01975, 0.02686, 0.99289]],
+    normal: [-0.00293, -0.00645, 0.00938],
+    second: [[0.37009, 0.88540, -0.25549], [0.25767, 0.63782, 0.10451], [-0.01950, 0.02741, 0.99209]],
+};
+
+const BRETTEL_TRITAN: Brettel = Brettel {
+    first: [[1.01277, 0.13548, -0.14826], [-0.01243, 0.86812, 0.14431], [0.07589, 0.80500, 0.11911]],
+    normal: [0.03901, -0.02788, -0.01113],
+    second: [[0.93678, 0.18979, -0.12657], [0.06154, 0.81526, 0.12320], [-0.37562, 1.12767, 0.24796]],
+};
+
+/// Viénot et al. (1999) projections for sRGB, in linear RGB.
+const VIENOT_PROTAN: Matrix = [[0.11238, 0.88762, 0.0], [0.11238, 0.88762, 0.0], [0.00401, -0.00401, 1.0]];
+const VIENOT_DEUTAN: Matrix = [[0.29275, 0.70725, 0.0], [0.29275, 0.70725, 0.0], [-0.02234, 0.02234, 1.0]];
+
+/// Simulates how colors appear to a viewer with a color vision deficiency.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CvdSimulator {
+    pub deficiency: Deficiency,
+    /// From 0 (normal vision) to 1 (complete loss, as in dichromacy).
+    pub severity: f64,
+    pub model: CvdModel,
+}
+
+impl CvdSimulator {
+    /// Creates a simulator for the complete form of `deficiency`, using the
+    /// default model.
+    pub fn new(deficiency: Deficiency) -> Self {
+        CvdSimulator {
+            deficiency,
+            severity: 1.0,
+            model: CvdModel::default(),
+        }
+    }
+
+    /// Returns the simulator with the given severity, clamped to `[0, 1]`.
+    pub fn with_severity(mut self, severity: f64) -> Self {
+        self.severity = severity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Returns the simulator using the given model.
+    pub fn with_model(mut self, model: CvdModel) -> Self {
+        self.model = model;
+        self
+    }
+
+    /// Returns the simulated appearance of `rgb`.
+    ///
+    /// Machado interpolates between its tabulated severities. The Brettel
+    /// and Viénot models simulate dichromacy only, so lower severities blend
+    /// the simulated color with the original in linear light, as does
+    /// achromatopsia.
+    pub fn simulate(self, rgb: RGB) -> RGB {
+        let linear = rgb.to_linear();
+        let v = [linear.r, linear.g, linear.b];
+        let severity = self.severity.clamp(0.0, 1.0);
+
+        let out = match (self.deficiency, self.model) {
+            (Deficiency::Achromatopsia, _) => {
+                let y = rgb.relative_luminance();
+                lerp(v, [y, y, y], severity)
+            },
+            (deficiency, CvdModel::Machado) => {
+                let table = match deficiency {
+                    Deficiency::Protan => &MACHADO_PROTAN,
+                    Deficiency::Deutan => &MACHADO_DEUTAN,
+                    _ => &MACHADO_TRITAN,
+                };
+                let at = |k: usize| if k == 0 { &IDENTITY } else { &table[k - 1] };
+                let x = severity * 10.0;
+                let i = (x.floor() as usize).min(9);
+                lerp(mul(at(i), v), mul(at(i + 1), v), x - i as f64)
+            },
+            (Deficiency::Protan, CvdModel::Vienot) => lerp(v, mul(&VIENOT_PROTAN, v), severity),
+            (Deficiency::Deutan, CvdModel::Vienot) => lerp(v, mul(&VIENOT_DEUTAN, v), severity),
+            (deficiency, _) => {
+                let params = match deficiency {
+                    Deficiency::Protan => &BRETTEL_PROTAN,
+                    Deficiency::Deutan => &BRETTEL_DEUTAN,
+                    _ => &BRETTEL_TRITAN,
+                };
+                let side: f64 = (0..3).map(|i| v[i] * params.normal[i]).sum();
+                let matrix = if side >= 0.0 { &params.first } else { &params.second };
+                lerp(v, mul(matrix, v), severity)
+            },
+        };
+        LinearRGB::new(out[0], out[1], out[2]).to_rgb()
+    }
+}
+
+impl RGB {
+    /// Simulates the appearance of this color under the complete form of
+    /// `deficiency`, using the default model.
+    pub fn simulate_cvd(self, deficiency: Deficiency) -> RGB {
+        CvdSimulator::new(deficiency).simulate(self)
+    }
+}
+
+/// How well a set of colors can be told apart by one kind of viewer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CvdCheck {
+    /// The simulated deficiency, or `None` for normal color vision.
+    pub simulator: Option<CvdSimulator>,
+    /// The colors as this viewer sees them.
+    pub simulated: Vec<RGB>,
+    /// Indices and CIEDE2000 difference of the two most similar colors, or
+    /// `None` if there are fewer than two colors.
+    pub closest: Option<(usize, usize, f64)>,
+    /// Pairs whose difference is below the report's threshold, most similar
+    /// first.
+    pub confusable: Vec<(usize, usize, f64)>,
+}
+
+impl CvdCheck {
+    fn new(simulator: Option<CvdSimulator>, colors: &[RGB], threshold: f64) -> Self {
+        let simulated: Vec<RGB> = match simulator {
+            Some(simulator) => colors.iter().map(|&rgb| simulator.simulate(rgb)).collect(),
+            None => colors.to_vec(),
+        };
+        let labs: Vec<Lab> = simulated.iter().map(|rgb| rgb.to_lab()).collect();
+
+        let mut pairs = Vec::new();
+        for i in 0..labs.len() {
+            for j in i + 1..labs.len() {
+                pairs.push((i, j, labs[i].delta_e_2000(labs[j])));
+            }
+        }
+        pairs.sort_by(|a, b| a.2.total_cmp(&b.2));
+
+        CvdCheck {
+            simulator,
+            simulated,
+            closest: pairs.first().copied(),
+            confusable: pairs.into_iter().take_while(|pair| pair.2 < threshold).collect(),
+        }
+    }
+
+    /// Returns the name of the kind of vision checked.
+    pub fn vision(&self) -> String {
+        match self.simulator {
+            Some(simulator) => simulator.deficiency.to_string(),
+            None => "normal".to_string(),
+        }
+    }
+}
+
+/// Pairwise distinguishability of a palette or colormap under normal vision
+/// and simulated color vision deficiencies.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CvdReport {
+    pub name: String,
+    pub colors: Vec<RGB>,
+    /// CIEDE2000 difference below which two colors count as
+    /// indistinguishable.
+    pub threshold: f64,
+    /// One check for normal vision followed by one per simulator.
+    pub checks: Vec<CvdCheck>,
+}
+
+impl CvdReport {
+    /// Compares every pair of `colors` as seen with normal vision and
+    /// through each of `simulators`, flagging pairs closer than `threshold`
+    /// in CIEDE2000.
+    pub fn new(name: impl Into<String>, colors: Vec<RGB>, simulators: &[CvdSimulator], threshold: f64) -> Self {
+        let checks = std::iter::once(None)
+            .chain(simulators.iter().copied().map(Some))
+            .map(|simulator| CvdCheck::new(simulator, &colors, threshold))
+            .collect();
+        CvdReport { name: name.into(), colors, threshold, checks }
+    }
+
+    /// Returns true if there are at least two colors and no pair is
+    /// indistinguishable for any viewer.
+    pub fn passed(&self) -> bool {
+        self.colors.len() >= 2 && self.checks.iter().all(|check| check.confusable.is_empty())
+    }
+
+    /// Formats a human-readable summary.
+    pub fn to_text(&self) -> String {
+        let mut out = String::new();
+        self.write_text(&mut out).expect("writing to a String cannot fail");
+        out
+    }
+
+    /// Writes the summary produced by [`to_text`](Self::to_text) to `out`.
+    pub fn write_text(&self, out: &mut impl Write) -> fmt::Result {
+        writeln!(out, "Colors:      {} ({})", self.name, self.colors.len())?;
+        writeln!(out, "Threshold:   ΔE2000 < {:.1} counts as indistinguishable", self.threshold)?;
+
+        writeln!(out, "\nVision           severity  model     min ΔE  closest pair       confusable")?;
+        for check in &self.checks {
+            let (severity, model) = match check.simulator {
+                Some(simulator) => (format!("{:.2}", simulator.severity), simulator.model.to_string()),
+                None => ("-".to_string(), "-".to_string()),
+            };
+            let (min, pair) = match check.closest {
+                Some((i, j, d)) => (format!("{:.2}", d),
+                    format!("{} {}", self.colors[i].to_hex(), self.colors[j].to_hex())),
+                None => ("-".to_string(), "-".to_string()),
+            };
+            writeln!(out, "{:<16} {:>8}  {:<8} {:>7}  {:<17}  {:>10}",
+                check.vision(), severity, model, min, pair, check.confusable.len())?;
+        }
+
+        if self.colors.len() < 2 {
+            return writeln!(out, "\nFewer than two colors, so there are no pairs to compare");
+        }
+        if self.passed() {
+            return writeln!(out, "\nAll colors are distinguishable");
+        }
+        writeln!(out, "\nIndistinguishable pairs:")?;
+        for check in &self.checks {
+            for &(i, j, d) in &check.confusable {
+                writeln!(out, "  {:<14} {:>2} {}  {:>2} {}  ΔE {:.2}  (seen as {} and {})",
+                    check.vision(), i, self.colors[i].to_hex(), j, self.colors[j].to_hex(), d,
+                    check.simulated[i].to_hex(), check.simulated[j].to_hex())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Formats the full report as JSON.
+    pub fn to_json(&self) -> String {
+        let hex_array = |colors: &[RGB]| {
+            let items: Vec<String> = colors.iter().map(|c| format!("\"{}\"", c.to_hex())).collect();
+            format!("[{}]", items.join(", "))
+        };
+        let pair = |&(i, j, d): &(usize, usize, f64)| {
+            format!("{{\"first\": {}, \"second\": {}, \"delta_e\": {:.4}}}", i, j, d)
+        };
+
+        let checks: Vec<String> = self.checks.iter()
+            .map(|check| {
+                let (severity, model) = match check.simulator {
+                    Some(simulator) => (format!("{:.4}", simulator.severity), format!("\"{}\"", simulator.model)),
+                    None => ("null".to_string(), "null".to_string()),
+                };
+                let confusable: Vec<String> = check.confusable.iter().map(pair).collect();
+                format!("{{\"vision\": \"{}\", \"severity\": {}, \"model\": {}, \"closest\": {}, \
+                    \"simulated\": {}, \"confusable\": [{}]}}",
+                    check.vision(), severity, model,
+                    check.closest.as_ref().map_or("null".to_string(), pair),
+                    hex_array(&check.simulated), confusable.join(", "))
+            })
+            .collect();
+
+        let fields = [
+            ("name", format!("\"{}\"", escape_json(&self.name))),
+            ("threshold", format!("{:.4}", self.threshold)),
+            ("passed", self.passed().to_string()),
+            ("colors", hex_array(&self.colors)),
+            ("checks", format!("[\n    {}\n  ]", checks.join(",\n    "))),
+        ];
+        let body: Vec<String> = fields.iter()
+            .map(|(key, value)| format!("  \"{}\": {}", key, value))
+            .collect();
+        format!("{{\n{}\n}}\n", body.join(",\n"))
+    }
+}
+
+impl Palette {
+    /// Checks that every pair of colors stays distinguishable through each of
+    /// `simulators`; see [`CvdReport::new`].
+    pub fn cvd_report(&self, simulators: &[CvdSimulator], threshold: f64) -> CvdReport {
+        CvdReport::new(self.name.clone(), self.colors.clone(), simulators, threshold)
+    }
+}
+
+impl Colormap {
+    /// Checks that `n` evenly spaced samples, as from
+    /// [`discretize`](Self::discretize), stay distinguishable through each
+    /// of `simulators`; see [`CvdReport::new`].
+    pub fn cvd_report(&self, n: usize, simulators: &[CvdSimulator], threshold: f64) -> CvdReport {
+        CvdReport::new(self.name.clone(), self.discretize(n), simulators, threshold)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_needs_two_colors() {
+        let sims = [CvdSimulator::new(Deficiency::Deutan)];
+        assert!(!CvdReport::new("empty", Vec::new(), &sims, 6.0).passed());
+        assert!(!CvdReport::new("one", vec![RGB::new(255, 0, 0)], &sims, 6.0).passed());
+        let pair = vec![RGB::new(0, 0, 0), RGB::new(255, 255, 255)];
+        assert!(CvdReport::new("pair", pair, &sims, 6.0).passed());
+    }
+
+    /// Pure red and green through the published matrices, applied in linear
+    /// light and re-encoded to 8-bit sRGB.
+    #[test]
+    fn matches_published_dichromat_matrices() {
+        let (red, green) = (RGB::new(255, 0, 0), RGB::new(0, 255, 0));
+        let cases = [
+            (CvdModel::Machado, Deficiency::Protan, RGB::new(109, 95, 0), RGB::new(255, 229, 0)),
+            (CvdModel::Machado, Deficiency::Deutan, RGB::new(163, 144, 0), RGB::new(239, 214, 58)),
+            (CvdModel::Brettel, Deficiency::Protan, RGB::new(106, 91, 14), RGB::new(255, 238, 0)),
+            (CvdModel::Brettel, Deficiency::Deutan, RGB::new(164, 139, 0), RGB::new(242, 209, 46)),
+            (CvdModel::Vienot, Deficiency::Protan, RGB::new(94, 94, 13), RGB::new(242, 242, 0)),
+            (CvdModel::Vienot, Deficiency::Deutan, RGB::new(147, 147, 0), RGB::new(219, 219, 41)),
+        ];
+        for (model, deficiency, seen_red, seen_green) in cases {
+            let simulator = CvdSimulator::new(deficiency).with_model(model);
+            assert_eq!(simulator.simulate(red), seen_red, "{} {}", model, deficiency);
+            assert_eq!(simulator.simulate(green), seen_green, "{} {}", model, deficiency);
+        }
+    }
+
+    #[test]
+    fn zero_severity_is_normal_vision() {
+        let levels = [0u8, 31, 128, 200, 255];
+        for model in [CvdModel::Machado, CvdModel::Brettel, CvdModel::Vienot] {
+            for deficiency in Deficiency::ALL {
+                let simulator = CvdSimulator::new(deficiency).with_model(model).with_severity(0.0);
+                for r in levels {
+                    for g in levels {
+                        for b in levels {
+                            let rgb = RGB::new(r, g, b);
+                            assert_eq!(simulator.simulate(rgb), rgb, "{} {}", model, deficiency);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
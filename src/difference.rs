@@ -0,0 +1,190 @@
+use crate::{Lab, RGB};
+
+impl Lab {
+    /// CIE76 color difference: Euclidean distance in CIELAB.
+    pub fn delta_e_76(self, other: Lab) -> f64 {
+        ((self.l - other.l).powi(2) + (self.a - other.a).powi(2) + (self.b - other.b).powi(2)).sqrt()
+    }
+
+    /// CIE94 color difference with the graphic arts weights, taking `self`
+    /// as the reference color.
+    pub fn delta_e_94(self, other: Lab) -> f64 {
+        let (c1, c2) = (self.a.hypot(self.b), other.a.hypot(other.b));
+        let dl = self.l - other.l;
+        let dc = c1 - c2;
+        let dh2 = ((self.a - other.a).powi(2) + (self.b - other.b).powi(2) - dc * dc).max(0.0);
+
+        let s_c = 1.0 + 0.045 * c1;
+        let s_h = 1.0 + 0.015 * c1;
+        (dl * dl + (dc / s_c).powi(2) + dh2 / (s_h * s_h)).sqrt()
+    }
+
+    /// CMC l:c color difference, taking `self` as the reference color.
+    ///
+    /// Use `l = 2, c = 1` for acceptability and `l = 1, c = 1` for
+    /// perceptibility.
+    pub fn delta_e_cmc(self, other: Lab, l: f64, c: f64) -> f64 {
+        let (c1, c2) = (self.a.hypot(self.b), other.a.hypot(other.b));
+        let dl = self.l - other.l;
+        let dc = c1 - c2;
+        let dh2 = ((self.a - other.a).powi(2) + (self.b - other.b).powi(2) - dc * dc).max(0.0);
+
+        let h1 = self.b.atan2(self.a).to_degrees().rem_euclid(360.0);
+        let s_l = if self.l < 16.0 { 0.511 } else { 0.040975 * self.l / (1.0 + 0.01765 * self.l) };
+        let s_c = 0.0638 * c1 / (1.0 + 0.0131 * c1) + 0.638;
+        let f = (c1.powi(4) / (c1.powi(4) + 1900.0)).sqrt();
+        let t = if (164.0..=345.0).contains(&h1) {
+            0.56 + (0.2 * (h1 + 168.0).to_radians().cos()).abs()
+        } else {
+            0.36 + (0.4 * (h1 + 35.0).to_radians().cos()).abs()
+        };
+        let s_h = s_c * (f * t + 1.0 - f);
+
+        ((dl / (l * s_l)).powi(2) + (dc / (c * s_c)).powi(2) + dh2 / (s_h * s_h)).sqrt()
+    }
+
+    /// CIEDE2000 color difference, with the parametric factors
+    /// `kL = kC = kH = 1`.
+    pub fn delta_e_2000(self, other: Lab) -> f64 {
+        let (l1, a1, b1) = (self.l, self.a, self.b);
+        let (l2, a2, b2) = (other.l, other.a, other.b);
+
+        let c_bar = (a1.hypot(b1) + a2.hypot(b2)) / 2.0;
+        let g = 0.5 * (1.0 - (c_bar.powi(7) / (c_bar.powi(7) + 25f64.powi(7))).sqrt());
+        let (a1, a2) = (a1 * (1.0 + g), a2 * (1.0 + g));
+        let (c1, c2) = (a1.hypot(b1), a2.hypot(b2));
+
+        let hue = |a: f64, b: f64| {
+            if a == 0.0 && b == 0.0 { 0.0 } else { b.atan2(a).to_degrees().rem_euclid(360.0) }
+        };
+        let (h1, h2) = (hue(a1, b1), hue(a2, b2));
+
+        let dl = l2 - l1;
+        let dc = c2 - c1;
+        let dh = if c1 * c2 == 0.0 {
+            0.0
+        } else if (h2 - h1).abs() <= 180.0 {
+            h2 - h1
+        } else if h2 - h1 > 180.0 {
+            h2 - h1 - 360.0
+        } else {
+            h2 - h1 + 360.0
+        };
+        let dh = 2.0 * (c1 * c2).sqrt() * (dh / 2.0).to_radians().sin();
+
+        let l_bar = (l1 + l2) / 2.0;
+        let c_bar = (c1 + c2) / 2.0;
+        let h_bar = if c1 * c2 == 0.0 {
+            h1 + h2
+        } else if (h1 - h2).abs() <= 180.0 {
+            (h1 + h2) / 2.0
+        } else if h1 + h2 < 360.0 {
+            (h1 + h2 + 360.0) / 2.0
+        } else {
+            (h1 + h2 - 360.0) / 2.0
+        };
+
+        let t = 1.0 - 0.17 * (h_bar - 30.0).to_radians().cos()
+            + 0.24 * (2.0 * h_bar).to_radians().cos()
+            + 0.32 * (3.0 * h_bar + 6.0).to_radians().cos()
+            - 0.20 * (4.0 * h_bar - 63.0).to_radians().cos();
+        let d_theta = 30.0 * (-((h_bar - 275.0) / 25.0).powi(2)).exp();
+        let r_c = 2.0 * (c_bar.powi(7) / (c_bar.powi(7) + 25f64.powi(7))).sqrt();
+        let s_l = 1.0 + 0.015 * (l_bar - 50.0).powi(2) / (20.0 + (l_bar - 50.0).powi(2)).sqrt();
+        let s_c = 1.0 + 0.045 * c_bar;
+        let s_h = 1.0 + 0.015 * c_bar * t;
+        let r_t = -(2.0 * d_theta).to_radians().sin() * r_c;
+
+        ((dl / s_l).powi(2) + (dc / s_c).powi(2) + (dh / s_h).powi(2)
+            + r_t * (dc / s_c) * (dh / s_h))
+            .sqrt()
+    }
+}
+
+impl RGB {
+    /// CIE76 color difference, computed in CIELAB with a D65 white.
+    pub fn delta_e_76(self, other: RGB) -> f64 {
+        self.to_lab().delta_e_76(other.to_lab())
+    }
+
+    /// CIE94 color difference (graphic arts), computed in CIELAB with a D65
+    /// white and `self` as the reference.
+    pub fn delta_e_94(self, other: RGB) -> f64 {
+        self.to_lab().delta_e_94(other.to_lab())
+    }
+
+    /// CMC l:c color difference, computed in CIELAB with a D65 white and
+    /// `self` as the reference.
+    pub fn delta_e_cmc(self, other: RGB, l: f64, c: f64) -> f64 {
+        self.to_lab().delta_e_cmc(other.to_lab(), l, c)
+    }
+
+    /// CIEDE2000 color difference, computed in CIELAB with a D65 white.
+    pub fn delta_e_2000(self, other: RGB) -> f64 {
+        self.to_lab().delta_e_2000(other.to_lab())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Test data from Sharma, Wu and Dalal, "The CIEDE2000 Color-Difference
+    /// Formula: Implementation Notes, Supplementary Test Data, and
+    /// Mathematical Observations" (2005), Table 1.
+    const SHARMA: [([f64; 3], [f64; 3], f64); 34] = [
+        ([50.0000, 2.6772, -79.7751], [50.0000, 0.0000, -82.7485], 2.0425),
+        ([50.0000, 3.1571, -77.2803], [50.0000, 0.0000, -82.7485], 2.8615),
+        ([50.0000, 2.8361, -74.0200], [50.0000, 0.0000, -82.7485], 3.4412),
+        ([50.0000, -1.3802, -84.2814], [50.0000, 0.0000, -82.7485], 1.0000),
+        ([50.0000, -1.1848, -84.8006], [50.0000, 0.0000, -82.7485], 1.0000),
+        ([50.0000, -0.9009, -85.5211], [50.0000, 0.0000, -82.7485], 1.0000),
+        ([50.0000, 0.0000, 0.0000], [50.0000, -1.0000, 2.0000], 2.3669),
+        ([50.0000, -1.0000, 2.0000], [50.0000, 0.0000, 0.0000], 2.3669),
+        ([50.0000, 2.4900, -0.0010], [50.0000, -2.4900, 0.0009], 7.1792),
+        ([50.0000, 2.4900, -0.0010], [50.0000, -2.4900, 0.0010], 7.1792),
+        ([50.0000, 2.4900, -0.0010], [50.0000, -2.4900, 0.0011], 7.2195),
+        ([50.0000, 2.4900, -0.0010], [50.0000, -2.4900, 0.0012], 7.2195),
+        ([50.0000, -0.0010, 2.4900], [50.0000, 0.0009, -2.4900], 4.8045),
+        ([50.0000, -0.0010, 2.4900], [50.0000, 0.0010, -2.4900], 4.8045),
+        ([50.0000, -0.0010, 2.4900], [50.0000, 0.0011, -2.4900], 4.7461),
+        ([50.0000, 2.5000, 0.0000], [50.0000, 0.0000, -2.5000], 4.3065),
+        ([50.0000, 2.5000, 0.0000], [73.0000, 25.0000, -18.0000], 27.1492),
+        ([50.0000, 2.5000, 0.0000], [61.0000, -5.0000, 29.0000], 22.8977),
+        ([50.0000, 2.5000, 0.0000], [56.0000, -27.0000, -3.0000], 31.9030),
+        ([50.0000, 2.5000, 0.0000], [58.0000, 24.0000, 15.0000], 19.4535),
+        ([50.0000, 2.5000, 0.0000], [50.0000, 3.1736, 0.5854], 1.0000),
+        ([50.0000, 2.5000, 0.0000], [50.0000, 3.2972, 0.0000], 1.0000),
+        ([50.0000, 2.5000, 0.0000], [50.0000, 1.8634, 0.5757], 1.0000),
+        ([50.0000, 2.5000, 0.0000], [50.0000, 3.2592, 0.3350], 1.0000),
+        ([60.2574, -34.0099, 36.2677], [60.4626, -34.1751, 39.4387], 1.2644),
+        ([63.0109, -31.0961, -5.8663], [62.8187, -29.7946, -4.0864], 1.2630),
+        ([61.2901, 3.7196, -5.3901], [61.4292, 2.2480, -4.9620], 1.8731),
+        ([35.0831, -44.1164, 3.7933], [35.0232, -40.0716, 1.5901], 1.8645),
+        ([22.7233, 20.0904, -46.6940], [23.0331, 14.9730, -42.5619], 2.0373),
+        ([36.4612, 47.8580, 18.3852], [36.2715, 50.5065, 21.2231], 1.4146),
+        ([90.8027, -2.0831, 1.4410], [91.1528, -1.6435, 0.0447], 1.4441),
+        ([90.9257, -0.5406, -0.9208], [88.6381, -0.8985, -0.7239], 1.5381),
+        ([6.7747, -0.2908, -2.4247], [5.8714, -0.0985, -2.2286], 0.6377),
+        ([2.0776, 0.0795, -1.1350], [0.9033, -0.0636, -0.5514], 0.9082),
+    ];
+
+    #[test]
+    fn delta_e_2000_matches_sharma_test_data() {
+        for (i, ([l1, a1, b1], [l2, a2, b2], expected)) in SHARMA.into_iter().enumerate() {
+            let (first, second) = (Lab::new(l1, a1, b1), Lab::new(l2, a2, b2));
+            let actual = first.delta_e_2000(second);
+            assert!((actual - expected).abs() < 5e-5, "pair {}: {:.4} != {:.4}", i + 1, actual, expected);
+            assert!((second.delta_e_2000(first) - actual).abs() < 1e-9, "pair {} is not symmetric", i + 1);
+        }
+    }
+
+    #[test]
+    fn identical_colors_do_not_differ() {
+        let rgb = RGB::new(70, 130, 180);
+        assert_eq!(rgb.delta_e_76(rgb), 0.0);
+        assert_eq!(rgb.delta_e_94(rgb), 0.0);
+        assert_eq!(rgb.delta_e_cmc(rgb, 2.0, 1.0), 0.0);
+        assert_eq!(rgb.delta_e_2000(rgb), 0.0);
+    }
+}
@@ -0,0 +1,151 @@
+use std::error::Error;
+use std::fmt;
+use std::ops::Range;
+
+/// The reason a color string could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The input was empty.
+    Empty,
+    /// A hex code or component list had the wrong number of elements.
+    InvalidLength {
+        expected: &'static str,
+        found: usize,
+    },
+    /// A hex code contained a non-hexadecimal character.
+    InvalidDigit(char),
+    /// A component was not a valid number.
+    InvalidComponent(&'static str),
+    /// A component was a valid number outside the allowed range.
+    OutOfRange(&'static str),
+    /// A color name was not found in any name table.
+    UnknownName(String),
+    /// The input is not in a recognized format, or uses an unsupported
+    /// function or color space.
+    UnsupportedSyntax(String),
+}
+
+/// An error from parsing a color string.
+///
+/// The span is a byte range into the original input that points at the
+/// offending part, so callers can report or underline it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseColorError {
+    kind: ParseErrorKind,
+    span: Range<usize>,
+}
+
+impl ParseColorError {
+    /// Creates an error covering the given byte range of the input.
+    pub fn new(kind: ParseErrorKind, span: Range<usize>) -> Self {
+        ParseColorError { kind, span }
+    }
+
+    /// Creates an error covering `part`, which must be a subslice of `input`.
+    pub(crate) fn at(kind: ParseErrorKind, input: &str, part: &str) -> Self {
+        let base = input.as_ptr() as usize;
+        let start = part.as_ptr() as usize;
+        let span = if start >= base && start + part.len() <= base + input.len() {
+            start - base..start - base + part.len()
+        } else {
+            0..input.len()
+        };
+        ParseColorError { kind, span }
+    }
+
+    /// Returns what went wrong.
+    pub fn kind(&self) -> &ParseErrorKind {
+        &self.kind
+    }
+
+    /// Returns the byte range of the input the error refers to.
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    /// Renders `input` with a `^^^` marker line under the offending span.
+    pub fn underline(&self, input: &str) -> String {
+        let start = input.get(..self.span.start).map_or(0, |s| s.chars().count());
+        let width = input.get(self.span.clone()).map_or(1, |s| s.chars().count()).max(1);
+        format!("{}\n{}{}", input, " ".repeat(start), "^".repeat(width))
+    }
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.kind {
+            ParseErrorKind::Empty => write!(f, "Empty color"),
+            ParseErrorKind::InvalidLength { expected, found } => {
+                write!(f, "Invalid length: expected {}, found {}", expected, found)
+            },
+            ParseErrorKind::InvalidDigit(c) => {
+                write!(f, "Invalid hex digit '{}' at position {}", c, self.span.start)
+            },
+            ParseErrorKind::InvalidComponent(component) => {
+                write!(f, "Invalid {} component at position {}", component, self.span.start)
+            },
+            ParseErrorKind::OutOfRange(component) => {
+                write!(f, "The {} component at position {} is out of range",
+                    component, self.span.start)
+            },
+            ParseErrorKind::UnknownName(name) => write!(f, "Unknown color name '{}'", name),
+            ParseErrorKind::UnsupportedSyntax(reason) => write!(f, "{}", reason),
+        }
+    }
+}
+
+impl Error for ParseColorError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RGBA;
+
+    fn error(input: &str) -> ParseColorError {
+        input.parse::<RGBA>().expect_err(input)
+    }
+
+    #[test]
+    fn reports_kind_and_span() {
+        let cases = [
+            ("", ParseErrorKind::Empty, 0..0),
+            ("#12345", ParseErrorKind::InvalidLength { expected: "3, 4, 6 or 8 hex digits", found: 5 }, 1..6),
+            ("#12G456", ParseErrorKind::InvalidDigit('G'), 3..4),
+            ("300,0,0", ParseErrorKind::OutOfRange("red"), 0..3),
+            ("notacolor", ParseErrorKind::UnknownName("notacolor".to_string()), 0..9),
+            ("rgb(255 0 zero)", ParseErrorKind::InvalidComponent("blue"), 10..14),
+            ("hsl(12x 50% 50%)", ParseErrorKind::InvalidComponent("hue"), 4..7),
+            ("rgb(1 2)", ParseErrorKind::InvalidLength { expected: "3 components", found: 2 }, 4..7),
+        ];
+        for (input, kind, span) in cases {
+            let e = error(input);
+            assert_eq!((e.kind(), e.span()), (&kind, span), "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn points_at_unsupported_syntax() {
+        assert_eq!(error("foo(1 2 3)").span(), 0..3);
+        assert_eq!(error("color(rec2020 1 0 0)").span(), 6..13);
+        assert_eq!(error("rgb(1, 2 3)").span(), 7..10);
+        // A missing parenthesis is reported at the end of the input
+        assert_eq!(error("rgb(255 0 0").span(), 11..11);
+        assert_eq!(error("  rgb(1 2 x)  ").span(), 10..11);
+    }
+
+    #[test]
+    fn displays_position() {
+        assert_eq!(error("#12G456").to_string(), "Invalid hex digit 'G' at position 3");
+        assert_eq!(error("rgb(255 0 zero)").to_string(), "Invalid blue component at position 10");
+        assert_eq!(error("300,0,0").to_string(), "The red component at position 0 is out of range");
+    }
+
+    #[test]
+    fn underlines_span() {
+        assert_eq!(error("#12G456").underline("#12G456"), "#12G456\n   ^");
+        assert_eq!(error("rgb(255 0 0").underline("rgb(255 0 0"), "rgb(255 0 0\n           ^");
+        // Columns count characters, not bytes
+        let input = "lab(é 0 0)";
+        assert_eq!(error(input).underline(input), "lab(é 0 0)\n    ^");
+    }
+}
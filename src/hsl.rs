@@ -0,0 +1,81 @@
+use std::str::FromStr;
+
+use crate::css::Function;
+use crate::ParseColorError;
+use crate::RGB;
+
+/// A color in the HSL (hue, saturation, lightness) model.
+///
+/// Hue is in degrees `[0, 360)`, saturation and lightness are percentages
+/// `[0, 100]`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HSL {
+    pub h: f64,
+    pub s: f64,
+    pub l: f64,
+}
+
+impl HSL {
+    /// Creates a new HSL color.
+    pub fn new(h: f64, s: f64, l: f64) -> Self {
+        HSL { h, s, l }
+    }
+
+    /// Converts to 8-bit sRGB.
+    pub fn to_rgb(self) -> RGB {
+        let h = self.h / 360.0;
+        let s = self.s / 100.0;
+        let l = self.l / 100.0;
+
+        let q = if l < 0.5 {
+            l * (1.0 + s)
+        } else {
+            l + s - l * s
+        };
+        let p = 2.0 * l - q;
+
+        fn hue_to_rgb(p: f64, q: f64, mut t: f64) -> u8 {
+            if t < 0.0 { t += 1.0 }
+            if t > 1.0 { t -= 1.0 }
+
+            let color = if t < 1.0/6.0 {
+                p + (q - p) * 6.0 * t
+            } else if t < 1.0/2.0 {
+                q
+            } else if t < 2.0/3.0 {
+                p + (q - p) * (2.0/3.0 - t) * 6.0
+            } else {
+                p
+            };
+
+            (color * 255.0).round() as u8
+        }
+
+        RGB {
+            r: hue_to_rgb(p, q, h + 1.0/3.0),
+            g: hue_to_rgb(p, q, h),
+            b: hue_to_rgb(p, q, h - 1.0/3.0),
+        }
+    }
+}
+
+impl From<RGB> for HSL {
+    fn from(rgb: RGB) -> Self {
+        rgb.to_hsl()
+    }
+}
+
+impl From<HSL> for RGB {
+    fn from(hsl: HSL) -> Self {
+        hsl.to_rgb()
+    }
+}
+
+impl FromStr for HSL {
+    type Err = ParseColorError;
+
+    /// Parses CSS `hsl(h s l)` or `hsla(h, s, l, a)`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Function::parse(s)?.hsl()
+    }
+}
@@ -0,0 +1,221 @@
+use std::str::FromStr;
+
+use crate::css::Function;
+use crate::ParseColorError;
+use crate::RGB;
+
+/// A color in the HSV/HSB (hue, saturation, value) model.
+///
+/// Hue is in degrees `[0, 360)`, saturation and value are percentages
+/// `[0, 100]`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HSV {
+    pub h: f64,
+    pub s: f64,
+    pub v: f64,
+}
+
+/// A color in the HWB (hue, whiteness, blackness) model used by CSS.
+///
+/// Hue is in degrees `[0, 360)`, whiteness and blackness are percentages
+/// `[0, 100]`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HWB {
+    pub h: f64,
+    pub w: f64,
+    pub b: f64,
+}
+
+impl HSV {
+    /// Creates a new HSV color.
+    pub fn new(h: f64, s: f64, v: f64) -> Self {
+        HSV { h, s, v }
+    }
+
+    /// Converts to 8-bit sRGB.
+    pub fn to_rgb(self) -> RGB {
+        let h = self.h.rem_euclid(360.0) / 60.0;
+        let s = (self.s / 100.0).clamp(0.0, 1.0);
+        let v = (self.v / 100.0).clamp(0.0, 1.0);
+
+        let c = v * s;
+        let x = c * (1.0 - (h % 2.0 - 1.0).abs());
+        let m = v - c;
+
+        let (r, g, b) = match h as u32 {
+            0 => (c, x, 0.0),
+            1 => (x, c, 0.0),
+            2 => (0.0, c, x),
+            3 => (0.0, x, c),
+            4 => (x, 0.0, c),
+            _ => (c, 0.0, x),
+        };
+
+        RGB {
+            r: ((r + m) * 255.0).round() as u8,
+            g: ((g + m) * 255.0).round() as u8,
+            b: ((b + m) * 255.0).round() as u8,
+        }
+    }
+
+    /// Converts to HWB.
+    pub fn to_hwb(self) -> HWB {
+        HWB {
+            h: self.h,
+            w: (100.0 - self.s) * self.v / 100.0,
+            b: 100.0 - self.v,
+        }
+    }
+}
+
+impl HWB {
+    /// Creates a new HWB color.
+    pub fn new(h: f64, w: f64, b: f64) -> Self {
+        HWB { h, w, b }
+    }
+
+    /// Converts to HSV. Whiteness and blackness summing past 100% are
+    /// normalized, yielding a gray.
+    pub fn to_hsv(self) -> HSV {
+        let mut w = self.w / 100.0;
+        let mut b = self.b / 100.0;
+        if w + b >= 1.0 {
+            let sum = w + b;
+            w /= sum;
+            b /= sum;
+        }
+
+        let v = 1.0 - b;
+        let s = if v == 0.0 { 0.0 } else { 1.0 - w / v };
+        HSV {
+            h: self.h,
+            s: s * 100.0,
+            v: v * 100.0,
+        }
+    }
+
+    /// Converts to 8-bit sRGB.
+    pub fn to_rgb(self) -> RGB {
+        self.to_hsv().to_rgb()
+    }
+}
+
+impl RGB {
+    /// Converts to the HSV model.
+    pub fn to_hsv(self) -> HSV {
+        let r = self.r as f64 / 255.0;
+        let g = self.g as f64 / 255.0;
+        let b = self.b as f64 / 255.0;
+
+        let max = r.max(g.max(b));
+        let min = r.min(g.min(b));
+        let delta = max - min;
+
+        // Hue is shared with HSL.
+        let h = self.to_hsl().h;
+        let s = if max == 0.0 { 0.0 } else { delta / max };
+
+        HSV {
+            h,
+            s: s * 100.0,
+            v: max * 100.0,
+        }
+    }
+
+    /// Converts to the HWB model.
+    pub fn to_hwb(self) -> HWB {
+        self.to_hsv().to_hwb()
+    }
+}
+
+impl From<RGB> for HSV {
+    fn from(rgb: RGB) -> Self {
+        rgb.to_hsv()
+    }
+}
+
+impl From<HSV> for RGB {
+    fn from(hsv: HSV) -> Self {
+        hsv.to_rgb()
+    }
+}
+
+impl From<RGB> for HWB {
+    fn from(rgb: RGB) -> Self {
+        rgb.to_hwb()
+    }
+}
+
+impl From<HWB> for RGB {
+    fn from(hwb: HWB) -> Self {
+        hwb.to_rgb()
+    }
+}
+
+impl FromStr for HSV {
+    type Err = ParseColorError;
+
+    /// Parses `hsv(h s v)`; `hsb(...)` is accepted as an alias.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Function::parse(s)?.hsv()
+    }
+}
+
+impl FromStr for HWB {
+    type Err = ParseColorError;
+
+    /// Parses CSS `hwb(h w b)`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Function::parse(s)?.hwb()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> impl Iterator<Item = RGB> {
+        (0..=255).step_by(15).flat_map(|r| {
+            (0..=255).step_by(15).flat_map(move |g| (0..=255).step_by(15).map(move |b| RGB::new(r, g, b)))
+        })
+    }
+
+    #[test]
+    fn converts_reference_colors() {
+        let cases = [
+            (RGB::new(255, 0, 0), [0.0, 100.0, 100.0], [0.0, 0.0, 0.0]),
+            (RGB::new(0, 128, 0), [120.0, 100.0, 50.2], [120.0, 0.0, 49.8]),
+            (RGB::new(70, 130, 180), [207.27, 61.11, 70.59], [207.27, 27.45, 29.41]),
+            (RGB::new(255, 255, 255), [0.0, 0.0, 100.0], [0.0, 100.0, 0.0]),
+        ];
+        for (rgb, [h, s, v], [hw, w, b]) in cases {
+            let (hsv, hwb) = (rgb.to_hsv(), rgb.to_hwb());
+            assert!((hsv.h - h).abs() < 0.01 && (hsv.s - s).abs() < 0.01 && (hsv.v - v).abs() < 0.01, "{:?}", hsv);
+            assert!((hwb.h - hw).abs() < 0.01 && (hwb.w - w).abs() < 0.01 && (hwb.b - b).abs() < 0.01, "{:?}", hwb);
+        }
+    }
+
+    #[test]
+    fn hsv_and_hwb_round_trip() {
+        for rgb in grid() {
+            assert_eq!(rgb.to_hsv().to_rgb(), rgb);
+            assert_eq!(rgb.to_hwb().to_rgb(), rgb);
+            assert_eq!(rgb.to_hwb().to_hsv().to_rgb(), rgb);
+        }
+    }
+
+    #[test]
+    fn excess_whiteness_and_blackness_give_gray() {
+        assert_eq!(HWB::new(0.0, 60.0, 60.0).to_rgb(), RGB::new(128, 128, 128));
+        assert_eq!(HWB::new(240.0, 100.0, 0.0).to_rgb(), RGB::new(255, 255, 255));
+        assert_eq!(HWB::new(240.0, 0.0, 100.0).to_rgb(), RGB::new(0, 0, 0));
+    }
+
+    #[test]
+    fn parses_hsv_hsb_and_hwb() {
+        assert_eq!("hsv(210 50% 80%)".parse::<HSV>().unwrap(), HSV::new(210.0, 50.0, 80.0));
+        assert_eq!("hsb(210, 50%, 80%)".parse::<HSV>().unwrap(), HSV::new(210.0, 50.0, 80.0));
+        assert_eq!("hwb(120 20% 40%)".parse::<HWB>().unwrap(), HWB::new(120.0, 20.0, 40.0));
+        assert!("hwb(120 20%)".parse::<HWB>().is_err());
+    }
+}
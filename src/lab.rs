@@ -0,0 +1,236 @@
+use std::str::FromStr;
+
+use crate::css::Function;
+use crate::ParseColorError;
+use crate::{WhitePoint, RGB, XYZ};
+
+const EPSILON: f64 = 216.0 / 24389.0;
+const KAPPA: f64 = 24389.0 / 27.0;
+
+/// A color in the CIE 1976 L\*a\*b\* space.
+///
+/// `l` is perceptual lightness in `[0, 100]`; `a` and `b` are the
+/// green–red and blue–yellow opponent axes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Lab {
+    pub l: f64,
+    pub a: f64,
+    pub b: f64,
+}
+
+/// A color in CIE LCh(ab), the cylindrical form of [`Lab`].
+///
+/// `c` is chroma and `h` is the hue angle in degrees `[0, 360)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LCh {
+    pub l: f64,
+    pub c: f64,
+    pub h: f64,
+}
+
+impl Lab {
+    /// Creates a new Lab color.
+    pub fn new(l: f64, a: f64, b: f64) -> Self {
+        Lab { l, a, b }
+    }
+
+    /// Converts D65-relative XYZ to Lab relative to `white`, adapting with
+    /// Bradford when `white` is not D65.
+    pub fn from_xyz(xyz: XYZ, white: WhitePoint) -> Self {
+        let xyz = xyz.adapt(WhitePoint::D65, white);
+        let w = white.xyz();
+
+        fn f(t: f64) -> f64 {
+            if t > EPSILON {
+                t.cbrt()
+            } else {
+                (KAPPA * t + 16.0) / 116.0
+            }
+        }
+
+        let fx = f(xyz.x / w.x);
+        let fy = f(xyz.y / w.y);
+        let fz = f(xyz.z / w.z);
+
+        Lab {
+            l: 116.0 * fy - 16.0,
+            a: 500.0 * (fx - fy),
+            b: 200.0 * (fy - fz),
+        }
+    }
+
+    /// Converts to D65-relative XYZ, treating this color as relative to `white`.
+    pub fn to_xyz(self, white: WhitePoint) -> XYZ {
+        let w = white.xyz();
+
+        let fy = (self.l + 16.0) / 116.0;
+        let fx = fy + self.a / 500.0;
+        let fz = fy - self.b / 200.0;
+
+        let finv = |f: f64| {
+            let f3 = f * f * f;
+            if f3 > EPSILON {
+                f3
+            } else {
+                (116.0 * f - 16.0) / KAPPA
+            }
+        };
+        let y = if self.l > KAPPA * EPSILON {
+            fy * fy * fy
+        } else {
+            self.l / KAPPA
+        };
+
+        XYZ::new(finv(fx) * w.x, y * w.y, finv(fz) * w.z).adapt(white, WhitePoint::D65)
+    }
+
+    /// Converts to 8-bit sRGB assuming a D65 reference white.
+    pub fn to_rgb(self) -> RGB {
+        self.to_xyz(WhitePoint::D65).to_rgb()
+    }
+
+    /// Converts to the cylindrical LCh form.
+    pub fn to_lch(self) -> LCh {
+        let c = self.a.hypot(self.b);
+        let h = self.b.atan2(self.a).to_degrees().rem_euclid(360.0);
+        LCh { l: self.l, c, h }
+    }
+}
+
+impl LCh {
+    /// Creates a new LCh color.
+    pub fn new(l: f64, c: f64, h: f64) -> Self {
+        LCh { l, c, h }
+    }
+
+    /// Converts to the rectangular Lab form.
+    pub fn to_lab(self) -> Lab {
+        let (sin, cos) = self.h.to_radians().sin_cos();
+        Lab {
+            l: self.l,
+            a: self.c * cos,
+            b: self.c * sin,
+        }
+    }
+
+    /// Converts to 8-bit sRGB assuming a D65 reference white.
+    pub fn to_rgb(self) -> RGB {
+        self.to_lab().to_rgb()
+    }
+}
+
+impl RGB {
+    /// Converts to CIELAB with a D65 reference white.
+    pub fn to_lab(self) -> Lab {
+        self.to_lab_with(WhitePoint::D65)
+    }
+
+    /// Converts to CIELAB relative to the given reference white.
+    pub fn to_lab_with(self, white: WhitePoint) -> Lab {
+        Lab::from_xyz(self.to_xyz(), white)
+    }
+
+    /// Converts to CIE LCh(ab) with a D65 reference white.
+    pub fn to_lch(self) -> LCh {
+        self.to_lab().to_lch()
+    }
+}
+
+impl From<Lab> for LCh {
+    fn from(lab: Lab) -> Self {
+        lab.to_lch()
+    }
+}
+
+impl From<LCh> for Lab {
+    fn from(lch: LCh) -> Self {
+        lch.to_lab()
+    }
+}
+
+impl From<RGB> for Lab {
+    fn from(rgb: RGB) -> Self {
+        rgb.to_lab()
+    }
+}
+
+impl From<Lab> for RGB {
+    fn from(lab: Lab) -> Self {
+        lab.to_rgb()
+    }
+}
+
+impl From<RGB> for LCh {
+    fn from(rgb: RGB) -> Self {
+        rgb.to_lch()
+    }
+}
+
+impl From<LCh> for RGB {
+    fn from(lch: LCh) -> Self {
+        lch.to_rgb()
+    }
+}
+
+impl FromStr for Lab {
+    type Err = ParseColorError;
+
+    /// Parses CSS `lab(L a b)`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Function::parse(s)?.lab()
+    }
+}
+
+impl FromStr for LCh {
+    type Err = ParseColorError;
+
+    /// Parses CSS `lch(L C h)`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Function::parse(s)?.lch()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_lab(actual: Lab, expected: [f64; 3]) {
+        let close = [actual.l, actual.a, actual.b].into_iter().zip(expected).all(|(a, e)| (a - e).abs() < 0.02);
+        assert!(close, "{:?} != {:?}", actual, expected);
+    }
+
+    #[test]
+    fn matches_reference_lab_at_d65_and_d50() {
+        // Bruce Lindbloom's color calculator, sRGB source, Bradford adaptation
+        let cases = [
+            (RGB::new(255, 0, 0), [53.24, 80.09, 67.20], [54.29, 80.81, 69.89]),
+            (RGB::new(0, 255, 0), [87.73, -86.18, 83.18], [87.82, -79.29, 80.99]),
+            (RGB::new(0, 0, 255), [32.30, 79.19, -107.86], [29.57, 68.30, -112.03]),
+            (RGB::new(255, 255, 255), [100.0, 0.0, 0.0], [100.0, 0.0, 0.0]),
+            (RGB::new(128, 128, 128), [53.59, 0.0, 0.0], [53.59, 0.0, 0.0]),
+        ];
+        for (rgb, d65, d50) in cases {
+            assert_lab(rgb.to_lab(), d65);
+            assert_lab(rgb.to_lab_with(WhitePoint::D50), d50);
+        }
+    }
+
+    #[test]
+    fn lch_is_polar_lab() {
+        let lch = RGB::new(255, 0, 0).to_lch();
+        assert!((lch.c - 104.55).abs() < 0.01 && (lch.h - 40.0).abs() < 0.01, "{:?}", lch);
+        let lab = LCh::new(50.0, 20.0, 270.0).to_lab();
+        assert!(lab.a.abs() < 1e-9 && (lab.b + 20.0).abs() < 1e-9, "{:?}", lab);
+    }
+
+    #[test]
+    fn lab_and_lch_round_trip() {
+        for v in (0..=255).step_by(17) {
+            for rgb in [RGB::new(v, 0, 255 - v), RGB::new(255, v, v / 3), RGB::new(v, v, v)] {
+                assert_eq!(rgb.to_lab().to_rgb(), rgb);
+                assert_eq!(rgb.to_lch().to_rgb(), rgb);
+                assert_eq!(rgb.to_lab_with(WhitePoint::D50).to_xyz(WhitePoint::D50).to_rgb(), rgb);
+            }
+        }
+    }
+}
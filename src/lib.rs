@@ -0,0 +1,44 @@
+//! Color manipulation utilities for scientific visualization.
+//!
+//! This crate backs the `rustcolors` command line tool and exposes the same
+//! color types and conversions for use from Rust code.
+
+#![allow(clippy::upper_case_acronyms)]
+
+mod cam16;
+mod cmyk;
+pub mod colormap;
+mod contrast;
+pub mod css;
+mod cvd;
+mod difference;
+mod error;
+mod hsl;
+mod hsv;
+mod lab;
+pub mod linear;
+pub mod named;
+mod oklab;
+pub mod palette;
+mod rgb;
+mod rgba;
+mod xyz;
+
+pub use cam16::Cam16Ucs;
+pub use cmyk::{CmykConverter, NaiveCmyk, CMYK};
+pub use colormap::{
+    Colormap, ColormapKind, ExportFormat, ImportFormat, InterpolationSpace, Neutral,
+};
+pub use contrast::{ContrastMethod, WcagLevel};
+pub use cvd::{CvdCheck, CvdModel, CvdReport, CvdSimulator, Deficiency};
+pub use error::{ParseColorError, ParseErrorKind};
+pub use hsl::HSL;
+pub use hsv::{HSV, HWB};
+pub use lab::{LCh, Lab};
+pub use linear::LinearRGB;
+pub use named::{NameTable, NamedColor};
+pub use oklab::{Oklab, Oklch};
+pub use palette::Palette;
+pub use rgb::{HueSpace, RGB};
+pub use rgba::RGBA;
+pub use xyz::{WhitePoint, XYZ};
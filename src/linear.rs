@@ -0,0 +1,96 @@
+use crate::RGB;
+
+/// Decodes a gamma-encoded sRGB component in `[0, 1]` to linear light.
+pub fn srgb_to_linear(c: f64) -> f64 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Encodes a linear-light component in `[0, 1]` with the sRGB transfer function.
+pub fn linear_to_srgb(c: f64) -> f64 {
+    if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// A color in linear-light sRGB, with components nominally in `[0, 1]`.
+///
+/// Values outside that range are kept so that out-of-gamut results of
+/// intermediate conversions are not silently clipped.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LinearRGB {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+}
+
+impl LinearRGB {
+    /// Creates a new linear RGB color.
+    pub fn new(r: f64, g: f64, b: f64) -> Self {
+        LinearRGB { r, g, b }
+    }
+
+    /// Returns true if every component lies within `[0, 1]`.
+    pub fn in_gamut(self) -> bool {
+        [self.r, self.g, self.b].iter().all(|c| (0.0..=1.0).contains(c))
+    }
+
+    /// Gamma-encodes and quantizes to 8-bit sRGB, clipping out-of-gamut values.
+    pub fn to_rgb(self) -> RGB {
+        fn encode(c: f64) -> u8 {
+            (linear_to_srgb(c.clamp(0.0, 1.0)) * 255.0).round() as u8
+        }
+
+        RGB {
+            r: encode(self.r),
+            g: encode(self.g),
+            b: encode(self.b),
+        }
+    }
+}
+
+impl From<RGB> for LinearRGB {
+    fn from(rgb: RGB) -> Self {
+        rgb.to_linear()
+    }
+}
+
+impl From<LinearRGB> for RGB {
+    fn from(linear: LinearRGB) -> Self {
+        linear.to_rgb()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transfer_function_matches_reference_values() {
+        let cases = [(0.0, 0.0), (0.04045, 0.0031308), (0.5, 0.2140411), (1.0, 1.0)];
+        for (encoded, linear) in cases {
+            assert!((srgb_to_linear(encoded) - linear).abs() < 1e-6, "{}", encoded);
+            assert!((linear_to_srgb(linear) - encoded).abs() < 1e-6, "{}", linear);
+        }
+    }
+
+    #[test]
+    fn every_8_bit_value_round_trips() {
+        for v in 0..=255 {
+            let rgb = RGB::new(v, v, v);
+            assert_eq!(rgb.to_linear().to_rgb(), rgb);
+        }
+    }
+
+    #[test]
+    fn out_of_gamut_values_are_kept_until_encoded() {
+        let linear = LinearRGB::new(1.2, -0.1, 0.5);
+        assert!(!linear.in_gamut());
+        assert_eq!(linear.to_rgb(), RGB::new(255, 0, 188));
+    }
+}
@@ -1,5 +1,14 @@
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
-use clap::{Parser, Subcommand};
+use clap::builder::RangedU64ValueParser;
+use clap::{Args, Parser, Subcommand};
+use rustcolors::colormap::FOUR_PHASE;
+use rustcolors::palette::DistinctOptions;
+use rustcolors::{
+    Colormap, ColormapKind, ContrastMethod, CvdModel, CvdReport, CvdSimulator, Deficiency,
+    ExportFormat, HueSpace, InterpolationSpace, NaiveCmyk, NameTable, Neutral, Palette,
+    ParseColorError, WcagLevel, WhitePoint, RGB, RGBA,
+};
 
 #[derive(Parser)]
 #[command(
@@ -13,197 +22,555 @@ struct Cli {
     command: Commands,
 }
 
+const COLOR_HELP: &str = "Input color in hex (#RGB/#RGBA/#RRGGBB/#RRGGBBAA), RGB (r,g,b), a \
+    name (steelblue, xkcd:dusty rose) or CSS functional notation (rgb(), hsl(), hwb(), lab(), \
+    lch(), oklab(), oklch(), color())";
+
 #[derive(Subcommand)]
 enum Commands {
     /// Display color harmonies (complement, triads, tetrads)
     Harmonies {
-        /// Input color in hex (#RRGGBB/RRGGBB) or RGB (r,g,b) format
-        #[arg(help = "Input color in hex (#RRGGBB/RRGGBB) or RGB (r,g,b) format")]
+        /// Input color
+        #[arg(help = COLOR_HELP)]
         color: String,
+        /// Color model used for hue rotation (hsl, oklch)
+        #[arg(long, default_value = "hsl")]
+        space: HueSpace,
+        /// Annotate each swatch with the nearest name from a table (css, x11, xkcd)
+        #[arg(long)]
+        names: Option<NameTable>,
+        #[command(flatten)]
+        cvd: CvdArgs,
     },
     /// Convert between color formats
     Convert {
-        /// Input color in hex (#RRGGBB/RRGGBB) or RGB (r,g,b) format
-        #[arg(help = "Input color in hex (#RRGGBB/RRGGBB) or RGB (r,g,b) format")]
+        /// Input color
+        #[arg(help = COLOR_HELP)]
         color: String,
-        /// Output format (hex, rgb, hsl)
-        #[arg(value_parser = ["hex", "rgb", "hsl"])]
+        /// Output format (hex, rgb, hsl, hsv, hwb, linear, xyz, lab, lch, oklab, oklch, cmyk, name)
+        #[arg(value_parser = [
+            "hex", "rgb", "hsl", "hsv", "hwb", "linear", "xyz", "lab", "lch", "oklab", "oklch",
+            "cmyk", "name",
+        ])]
         format: String,
+        /// Reference white for lab and lch output (d50, d55, d65, d75, a, e)
+        #[arg(long, default_value = "d65")]
+        white: WhitePoint,
+        /// Percentage of the gray component printed with black ink (cmyk output)
+        #[arg(long, default_value_t = 100.0, value_parser = percentage)]
+        black_generation: f64,
+        /// Percentage of black ink removed from the colored inks (cmyk output)
+        #[arg(long, default_value_t = 100.0, value_parser = percentage)]
+        ucr: f64,
+        /// Name table for name output and swatch annotations (css, x11, xkcd)
+        #[arg(long)]
+        names: Option<NameTable>,
+        #[command(flatten)]
+        cvd: CvdArgs,
+    },
+    /// Preview and sample a colormap, or list the built-in ones
+    #[command(args_conflicts_with_subcommands = true)]
+    Colormap {
+        #[command(subcommand)]
+        action: Option<ColormapCommand>,
+        /// Built-in colormap name or a .cpt, ParaView .json, .csv or ImageJ .lut file
+        name: Option<String>,
+        /// Print this many evenly spaced samples as hex codes
+        #[arg(long, value_parser = at_least(1))]
+        steps: Option<usize>,
+        /// Show the most legible text color on each sample (11 samples unless --steps is given)
+        #[arg(long, requires = "name")]
+        annotate_text_colors: bool,
+        /// Candidate text color for --annotate-text-colors; may be repeated [default: black, white]
+        #[arg(long, help = COLOR_HELP, requires = "annotate_text_colors")]
+        text_color: Vec<String>,
+        /// Contrast measure used to choose text colors (wcag, apca)
+        #[arg(long, default_value = "wcag", requires = "annotate_text_colors")]
+        contrast: ContrastMethod,
+        #[command(flatten)]
+        shape: ShapeArgs,
+    },
+    /// Measure how different two colors are (ΔE76, ΔE94, ΔE2000, CMC l:c)
+    Diff {
+        /// Reference color
+        #[arg(help = COLOR_HELP)]
+        first: String,
+        /// Sample color
+        #[arg(help = COLOR_HELP)]
+        second: String,
+        /// Lightness and chroma weights L:C for the CMC difference
+        #[arg(long, value_parser = parse_bounds, default_value = "2:1")]
+        cmc: (f64, f64),
+    },
+    /// Check text legibility with WCAG 2 contrast ratio and APCA lightness contrast
+    Contrast {
+        /// Text color; a translucent color is blended over the background
+        #[arg(help = COLOR_HELP)]
+        text: String,
+        /// Background color
+        #[arg(help = COLOR_HELP)]
+        background: String,
+        /// Sample text to render
+        #[arg(long, default_value = "The quick brown fox jumps over the lazy dog")]
+        sample: String,
+    },
+    /// Print a qualitative palette for categorical data, or list the built-in ones
+    #[command(args_conflicts_with_subcommands = true)]
+    Palette {
+        #[command(subcommand)]
+        action: Option<PaletteCommand>,
+        /// Built-in palette name
+        name: Option<String>,
+        /// Use only the first N colors
+        #[arg(long, short = 'n', value_parser = at_least(1))]
+        count: Option<usize>,
+        /// Annotate each swatch with the nearest name from a table (css, x11, xkcd)
+        #[arg(long)]
+        names: Option<NameTable>,
+    },
+    /// Check that a palette or colormap stays distinguishable with color vision deficiencies
+    ///
+    /// Exits with status 2 if any two colors are closer than the threshold
+    /// for any viewer, including one with normal vision.
+    CvdCheck {
+        /// Built-in palette or colormap name, or a .cpt, ParaView .json, .csv or ImageJ .lut file
+        name: String,
+        /// Check the first N colors of a palette, or N evenly spaced samples of a colormap
+        /// [default: all palette colors, 7 colormap samples]
+        #[arg(long, short = 'n', value_parser = at_least(2))]
+        count: Option<usize>,
+        /// Deficiencies to simulate, comma separated (protan, deutan, tritan, achromatopsia)
+        #[arg(long, value_delimiter = ',', default_value = "protan,deutan,tritan")]
+        cvd: Vec<Deficiency>,
+        /// Severity of the simulated deficiencies, from 0 (normal vision) to 1 (dichromacy)
+        #[arg(long, default_value_t = 1.0, value_parser = fraction)]
+        severity: f64,
+        /// Simulation model (machado, brettel, vienot)
+        #[arg(long, default_value = "machado")]
+        cvd_model: CvdModel,
+        /// Smallest CIEDE2000 difference at which two colors count as distinguishable
+        #[arg(long, default_value_t = 6.0)]
+        threshold: f64,
+        /// Print the full report as JSON
+        #[arg(long)]
+        json: bool,
+        #[command(flatten)]
+        shape: ShapeArgs,
     },
 }
 
-#[derive(Debug, Clone, Copy)]
-struct RGB {
-    r: u8,
-    g: u8,
-    b: u8,
+#[derive(Subcommand)]
+enum PaletteCommand {
+    /// Export a palette for matplotlib, ParaView, gnuplot, GMT or NCL
+    Export {
+        /// Built-in palette name
+        name: String,
+        /// Output format (matplotlib, paraview-xml, paraview-json, gnuplot, gmt, ncl)
+        #[arg(long, short)]
+        format: ExportFormat,
+        /// Use only the first N colors
+        #[arg(long, short = 'n', value_parser = at_least(1))]
+        count: Option<usize>,
+        /// Write to this file instead of standard output
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+    /// Generate colors that are as distinct from each other as possible (CIEDE2000)
+    Distinct {
+        /// Number of colors
+        #[arg(long, short = 'n', value_parser = at_least(1))]
+        count: usize,
+        /// Allowed CIELAB lightness range MIN:MAX
+        #[arg(long, value_parser = parse_bounds, default_value = "25:90")]
+        lightness: (f64, f64),
+        /// Allowed CIELAB chroma range MIN:MAX
+        #[arg(long, value_parser = parse_bounds, default_value = "20:150")]
+        chroma: (f64, f64),
+        /// Avoid hues (LCh degrees) in START:END; may be repeated, and wraps through 0
+        #[arg(long, value_parser = parse_bounds)]
+        exclude_hue: Vec<(f64, f64)>,
+        /// A color the palette must contain; may be repeated
+        #[arg(long, help = COLOR_HELP)]
+        include: Vec<String>,
+        /// Seed for the search; the same seed gives the same palette
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Name of the generated palette
+        #[arg(long, default_value = "distinct")]
+        name: String,
+        /// Export instead of printing (matplotlib, paraview-xml, paraview-json, gnuplot, gmt, ncl)
+        #[arg(long, short)]
+        format: Option<ExportFormat>,
+        /// Write the export to this file instead of standard output
+        #[arg(long, short, requires = "format")]
+        output: Option<PathBuf>,
+        /// Annotate each swatch with the nearest name from a table (css, x11, xkcd)
+        #[arg(long)]
+        names: Option<NameTable>,
+    },
+}
+
+#[derive(Subcommand)]
+enum ColormapCommand {
+    /// Export a colormap for matplotlib, ParaView, gnuplot, GMT or NCL
+    Export {
+        /// Built-in colormap name or a .cpt, ParaView .json, .csv or ImageJ .lut file
+        name: String,
+        /// Output format (matplotlib, paraview-xml, paraview-json, gnuplot, gmt, ncl)
+        #[arg(long, short)]
+        format: ExportFormat,
+        /// Number of evenly spaced samples to write
+        #[arg(long, default_value_t = 256, value_parser = at_least(2))]
+        steps: usize,
+        /// Write to this file instead of standard output
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+        #[command(flatten)]
+        shape: ShapeArgs,
+    },
+    /// Build a sequential colormap with linear lightness through anchor colors
+    Sequential {
+        /// Anchor colors, ordered from one end of the lightness range to the other
+        #[arg(required = true, num_args = 2.., help = COLOR_HELP)]
+        colors: Vec<String>,
+        /// Perceptual space to interpolate in (oklab, cam16)
+        #[arg(long, default_value = "oklab")]
+        space: InterpolationSpace,
+        #[command(flatten)]
+        output: GeneratedArgs,
+    },
+    /// Build a diverging colormap through a neutral midpoint
+    Diverging {
+        /// Color at the low end
+        #[arg(long, help = COLOR_HELP)]
+        from: String,
+        /// Color at the high end, `complement`, or `rotate:DEGREES` to rotate the hue of --from
+        #[arg(long, default_value = "complement")]
+        to: String,
+        /// Neutral color at the midpoint (white, gray, black)
+        #[arg(long, default_value = "white")]
+        neutral: Neutral,
+        /// Perceptual space to interpolate in (oklab, cam16)
+        #[arg(long, default_value = "oklab")]
+        space: InterpolationSpace,
+        /// Color model used for rotate:DEGREES (hsl, oklch)
+        #[arg(long, default_value = "hsl")]
+        hue_space: HueSpace,
+        #[command(flatten)]
+        output: GeneratedArgs,
+    },
+    /// Build a cyclic colormap: a constant-lightness hue circle, or a loop through anchor colors
+    Cyclic {
+        /// Anchor colors to cycle through instead of a hue circle
+        #[arg(help = COLOR_HELP)]
+        colors: Vec<String>,
+        /// Cycle through magenta, yellow, green and blue, like four-phase maps
+        #[arg(long, conflicts_with_all = ["colors", "lightness"])]
+        four_phase: bool,
+        /// Lightness of the hue circle, in percent
+        #[arg(long, default_value_t = 70.0)]
+        lightness: f64,
+        /// Perceptual space to interpolate in (oklab, cam16)
+        #[arg(long, default_value = "oklab")]
+        space: InterpolationSpace,
+        #[command(flatten)]
+        output: GeneratedArgs,
+    },
+    /// Report the lightness profile, perceptual uniformity and seam of a colormap
+    Analyze {
+        /// Built-in colormap name or a .cpt, ParaView .json, .csv or ImageJ .lut file
+        name: String,
+        /// Number of evenly spaced samples to analyze
+        #[arg(long, default_value_t = 256, value_parser = at_least(2))]
+        samples: usize,
+        /// Largest allowed deviation of a local step from the mean step, in percent
+        #[arg(long, default_value_t = 50.0)]
+        tolerance: f64,
+        /// Print the full report as JSON
+        #[arg(long)]
+        json: bool,
+        /// Exit with status 2 if any issues are found
+        #[arg(long)]
+        strict: bool,
+        #[command(flatten)]
+        shape: ShapeArgs,
+    },
 }
 
-#[derive(Debug, Clone, Copy)]
-struct HSL {
-    h: f64,
-    s: f64,
-    l: f64,
+/// Options for naming, previewing and exporting a generated colormap.
+#[derive(Args)]
+struct GeneratedArgs {
+    /// Name of the generated colormap
+    #[arg(long, default_value = "custom")]
+    name: String,
+    /// Print this many evenly spaced samples, or write this many when exporting
+    #[arg(long, value_parser = at_least(2))]
+    steps: Option<usize>,
+    /// Export instead of previewing (matplotlib, paraview-xml, paraview-json, gnuplot, gmt, ncl)
+    #[arg(long, short)]
+    format: Option<ExportFormat>,
+    /// Write the export to this file instead of standard output
+    #[arg(long, short, requires = "format")]
+    output: Option<PathBuf>,
 }
 
-impl RGB {
-    fn to_hex(&self) -> String {
-        format!("#{:02X}{:02X}{:02X}", self.r, self.g, self.b)
+impl GeneratedArgs {
+    fn show(&self, colormap: Result<Colormap, String>) {
+        let colormap = colormap.unwrap_or_else(|e| {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        });
+        match self.format {
+            Some(format) => {
+                export_colormap(&colormap, format, self.steps.unwrap_or(256), self.output.as_deref())
+            },
+            None => preview_colormap(&colormap, self.steps),
+        }
     }
+}
 
-    fn to_ansi_color_block(&self) -> String {
-        // Create a wider color block using RGB background color
-        format!("\x1b[48;2;{};{};{}m        \x1b[0m", self.r, self.g, self.b)
+/// Options that adjust how a colormap is sampled.
+#[derive(Args)]
+struct ShapeArgs {
+    /// Reverse the colormap
+    #[arg(long)]
+    reverse: bool,
+    /// Use only the sub-range START:END of the colormap, within 0:1
+    #[arg(long, value_parser = parse_range)]
+    range: Option<(f64, f64)>,
+    /// Color space to interpolate in (srgb, linear, oklab, cam16)
+    #[arg(long, default_value = "srgb")]
+    interpolation: InterpolationSpace,
+    /// Treat the colormap as this kind (sequential, diverging, cyclic, rainbow)
+    /// [default: the built-in kind, or inferred for files]
+    #[arg(long)]
+    kind: Option<ColormapKind>,
+}
+
+impl ShapeArgs {
+    /// Returns true if any option was changed from its default.
+    fn is_set(&self) -> bool {
+        self.reverse
+            || self.range.is_some()
+            || self.interpolation != InterpolationSpace::default()
+            || self.kind.is_some()
     }
 
-    fn display_with_color(&self) -> String {
-        format!("{} {}", self.to_ansi_color_block(), self.to_hex())
+    fn apply(&self, colormap: Colormap) -> Colormap {
+        let mut colormap = colormap.with_interpolation(self.interpolation);
+        if let Some(kind) = self.kind {
+            colormap.kind = kind;
+        }
+        if let Some((start, end)) = self.range {
+            colormap = colormap.truncated(start, end);
+        }
+        if self.reverse {
+            colormap = colormap.reversed();
+        }
+        colormap
     }
+}
 
-    fn to_hsl(&self) -> HSL {
-        let r = self.r as f64 / 255.0;
-        let g = self.g as f64 / 255.0;
-        let b = self.b as f64 / 255.0;
+/// Options for showing simulated color vision deficiencies next to a swatch.
+#[derive(Args)]
+struct CvdArgs {
+    /// Also show each color as seen with these deficiencies, comma separated
+    /// (protan, deutan, tritan, achromatopsia)
+    #[arg(long, value_delimiter = ',')]
+    cvd: Vec<Deficiency>,
+    /// Severity of the simulated deficiencies, from 0 (normal vision) to 1 (dichromacy)
+    #[arg(long, default_value_t = 1.0, value_parser = fraction, requires = "cvd")]
+    severity: f64,
+    /// Simulation model (machado, brettel, vienot)
+    #[arg(long, default_value = "machado", requires = "cvd")]
+    cvd_model: CvdModel,
+}
 
-        let max = r.max(g.max(b));
-        let min = r.min(g.min(b));
-        let delta = max - min;
+impl CvdArgs {
+    /// Formats the simulated swatches of `rgb`, or nothing if no
+    /// deficiencies were requested.
+    fn swatches(&self, rgb: RGB) -> String {
+        self.cvd.iter()
+            .map(|&deficiency| {
+                let simulated = CvdSimulator::new(deficiency)
+                    .with_severity(self.severity)
+                    .with_model(self.cvd_model)
+                    .simulate(rgb);
+                format!("  {}: {}", deficiency, simulated.display_with_color())
+            })
+            .collect()
+    }
+}
 
-        let mut h = 0.0;
-        let mut s = 0.0;
-        let l = (max + min) / 2.0;
+/// Resolves a built-in colormap name or a path to a colormap file.
+fn load_colormap(name: &str, shape: &ShapeArgs) -> Colormap {
+    let path = Path::new(name);
+    let colormap = if path.is_file() {
+        Colormap::load(path).map_err(|e| format!("{}: {}", path.display(), e))
+    } else {
+        name.parse::<Colormap>()
+    };
+    let colormap = colormap.unwrap_or_else(|e| {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    });
+    shape.apply(colormap)
+}
 
-        if delta != 0.0 {
-            s = if l < 0.5 {
-                delta / (max + min)
-            } else {
-                delta / (2.0 - max - min)
-            };
+/// Resolves a built-in palette name, keeping only the first `count` colors.
+fn load_palette(name: &str, count: Option<usize>) -> Palette {
+    let palette = name.parse::<Palette>().unwrap_or_else(|e| {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    });
+    match count {
+        Some(count) if count > palette.colors.len() => {
+            eprintln!("Error: Palette '{}' has only {} colors", palette.name, palette.colors.len());
+            std::process::exit(1);
+        },
+        Some(count) => palette.first(count),
+        None => palette,
+    }
+}
 
-            h = match max {
-                x if x == r => ((g - b) / delta) + (if g < b { 6.0 } else { 0.0 }),
-                x if x == g => ((b - r) / delta) + 2.0,
-                _ => ((r - g) / delta) + 4.0,
-            };
+/// Writes `text` to `output`, or to standard output.
+fn write_output(text: &str, output: Option<&Path>) {
+    match output {
+        Some(path) => std::fs::write(path, text).unwrap_or_else(|e| {
+            eprintln!("Error writing {}: {}", path.display(), e);
+            std::process::exit(1);
+        }),
+        None => print!("{}", text),
+    }
+}
 
-            h *= 60.0;
+fn parse_color<T: FromStr<Err = ParseColorError>>(color: &str) -> T {
+    T::from_str(color).unwrap_or_else(|e| {
+        eprintln!("Error parsing color: {}", e);
+        for line in e.underline(color).lines() {
+            eprintln!("  {}", line);
         }
+        std::process::exit(1);
+    })
+}
 
-        HSL {
-            h,
-            s: s * 100.0,
-            l: l * 100.0,
-        }
+/// Resolves the `--to` color of a diverging colormap relative to `from`.
+fn parse_target(from: RGB, to: &str, space: HueSpace) -> RGB {
+    if to.trim().eq_ignore_ascii_case("complement") {
+        return from.complement();
     }
-
-    fn complement(&self) -> RGB {
-        RGB {
-            r: 255 - self.r,
-            g: 255 - self.g,
-            b: 255 - self.b,
-        }
+    match to.trim().strip_prefix("rotate:") {
+        Some(degrees) => {
+            let degrees = degrees.trim().parse::<f64>().unwrap_or_else(|_| {
+                eprintln!("Error: '{}' is not a hue rotation in degrees", degrees);
+                std::process::exit(1);
+            });
+            from.rotate_hue_in(space, degrees.rem_euclid(360.0))
+        },
+        None => parse_color(to),
     }
+}
 
-    fn rotate_hue(&self, degrees: f64) -> RGB {
-        let mut hsl = self.to_hsl();
-        hsl.h = (hsl.h + degrees) % 360.0;
-        hsl.to_rgb()
+/// Formats alpha as a CSS-style `" / a"` suffix, or nothing when opaque.
+fn alpha_suffix(rgba: RGBA) -> String {
+    if rgba.is_opaque() {
+        String::new()
+    } else {
+        format!(" / {:.2}", rgba.a)
     }
+}
 
-    fn triads(&self) -> Vec<RGB> {
-        vec![
-            *self,
-            self.rotate_hue(120.0),
-            self.rotate_hue(240.0),
-        ]
-    }
+/// Accepts counts of at least `min`.
+fn at_least(min: u64) -> RangedU64ValueParser<usize> {
+    RangedU64ValueParser::new().range(min..)
+}
 
-    fn tetrads(&self) -> Vec<RGB> {
-        vec![
-            *self,
-            self.rotate_hue(90.0),
-            self.rotate_hue(180.0),
-            self.rotate_hue(270.0),
-        ]
-    }
+/// Parses a percentage in `[0, 100]`.
+fn percentage(s: &str) -> Result<f64, String> {
+    s.trim().parse::<f64>()
+        .ok()
+        .filter(|v| (0.0..=100.0).contains(v))
+        .ok_or_else(|| format!("'{}' is not a number between 0 and 100", s))
 }
 
-impl HSL {
-    fn to_rgb(&self) -> RGB {
-        let h = self.h / 360.0;
-        let s = self.s / 100.0;
-        let l = self.l / 100.0;
-
-        let q = if l < 0.5 {
-            l * (1.0 + s)
-        } else {
-            l + s - l * s
-        };
-        let p = 2.0 * l - q;
-
-        fn hue_to_rgb(p: f64, q: f64, mut t: f64) -> u8 {
-            if t < 0.0 { t += 1.0 }
-            if t > 1.0 { t -= 1.0 }
-
-            let color = if t < 1.0/6.0 {
-                p + (q - p) * 6.0 * t
-            } else if t < 1.0/2.0 {
-                q
-            } else if t < 2.0/3.0 {
-                p + (q - p) * (2.0/3.0 - t) * 6.0
-            } else {
-                p
-            };
+/// Parses a fraction in `[0, 1]`.
+fn fraction(s: &str) -> Result<f64, String> {
+    s.trim().parse::<f64>()
+        .ok()
+        .filter(|v| (0.0..=1.0).contains(v))
+        .ok_or_else(|| format!("'{}' is not a number between 0 and 1", s))
+}
 
-            (color * 255.0).round() as u8
-        }
+/// Parses a `START:END` sub-range of `[0, 1]`.
+fn parse_range(s: &str) -> Result<(f64, f64), String> {
+    let (start, end) = s.split_once(':')
+        .ok_or_else(|| "Expected START:END, e.g. 0.2:0.9".to_string())?;
+    Ok((fraction(start)?, fraction(end)?))
+}
 
-        RGB {
-            r: hue_to_rgb(p, q, h + 1.0/3.0),
-            g: hue_to_rgb(p, q, h),
-            b: hue_to_rgb(p, q, h - 1.0/3.0),
+/// Prints a colormap preview and, optionally, `steps` samples as hex codes.
+fn preview_colormap(colormap: &Colormap, steps: Option<usize>) {
+    println!("{} ({})", colormap.name, colormap.kind);
+    println!("{}", colormap.preview(64));
+    if let Some(steps) = steps {
+        for color in colormap.discretize(steps) {
+            println!("{}", color.display_with_color());
         }
     }
 }
 
-impl FromStr for RGB {
-    type Err = String;
+/// Writes a colormap export to `output`, or to standard output.
+fn export_colormap(colormap: &Colormap, format: ExportFormat, steps: usize, output: Option<&Path>) {
+    write_output(&colormap.export(format, steps), output);
+}
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let s = s.trim();
-        
-        // Handle hex format (with or without #)
-        if s.starts_with('#') || s.chars().all(|c| c.is_ascii_hexdigit()) {
-            let hex = if s.starts_with('#') { &s[1..] } else { s };
-            
-            if hex.len() != 6 {
-                return Err("Invalid hex color format. Expected RRGGBB or #RRGGBB".to_string());
-            }
-            
-            let r = u8::from_str_radix(&hex[0..2], 16)
-                .map_err(|_| "Invalid red component")?;
-            let g = u8::from_str_radix(&hex[2..4], 16)
-                .map_err(|_| "Invalid green component")?;
-            let b = u8::from_str_radix(&hex[4..6], 16)
-                .map_err(|_| "Invalid blue component")?;
-
-            Ok(RGB { r, g, b })
-        } else {
-            // Parse RGB format (r,g,b)
-            let parts: Vec<&str> = s.split(',').collect();
-            if parts.len() != 3 {
-                return Err("Invalid RGB format. Expected r,g,b".to_string());
-            }
+/// Parses a `MIN:MAX` pair of numbers.
+fn parse_bounds(s: &str) -> Result<(f64, f64), String> {
+    let (min, max) = s.split_once(':')
+        .ok_or_else(|| "Expected MIN:MAX, e.g. 30:80".to_string())?;
+    let parse = |v: &str| v.trim().parse::<f64>()
+        .map_err(|_| format!("'{}' is not a number", v));
+    Ok((parse(min)?, parse(max)?))
+}
 
-            let r = parts[0].trim().parse()
-                .map_err(|_| "Invalid red component")?;
-            let g = parts[1].trim().parse()
-                .map_err(|_| "Invalid green component")?;
-            let b = parts[2].trim().parse()
-                .map_err(|_| "Invalid blue component")?;
+/// Describes a CIEDE2000 difference in plain language.
+fn verdict(delta_e: f64) -> &'static str {
+    match delta_e {
+        d if d < 1.0 => "Not perceptible by the human eye",
+        d if d < 2.0 => "Perceptible only on close inspection",
+        d if d < 10.0 => "Perceptible at a glance",
+        d if d < 50.0 => "Clearly different colors",
+        _ => "Very different, nearly opposite colors",
+    }
+}
 
-            Ok(RGB { r, g, b })
-        }
+/// Describes the use an APCA Lc value is sufficient for.
+fn apca_guidance(lc: f64) -> &'static str {
+    match lc.abs() {
+        lc if lc >= 90.0 => "preferred for body text",
+        lc if lc >= 75.0 => "minimum for body text",
+        lc if lc >= 60.0 => "minimum for content text",
+        lc if lc >= 45.0 => "minimum for large or bold text",
+        lc if lc >= 30.0 => "minimum for non-text elements",
+        lc if lc >= 15.0 => "minimum for non-semantic elements",
+        _ => "not legible",
+    }
+}
+
+/// Prints the colors of `report` as each viewer sees them.
+fn print_cvd_strips(report: &CvdReport) {
+    for check in &report.checks {
+        let strip: String = check.simulated.iter().map(|color| color.to_ansi_color_cells(4)).collect();
+        println!("{:<14} {}", check.vision(), strip);
+    }
+    println!();
+}
+
+/// Formats a swatch with its hex code and, if requested, its nearest name.
+fn display_swatch(rgb: RGB, names: Option<NameTable>) -> String {
+    match names {
+        Some(table) => format!("{}  {}", rgb.display_with_color(), rgb.nearest_name(table)),
+        None => rgb.display_with_color(),
     }
 }
 
@@ -211,42 +578,271 @@ fn main() {
     let cli = Cli::parse();
 
     match cli.command {
-        Commands::Harmonies { color } => {
-            let rgb = RGB::from_str(&color).unwrap_or_else(|e| {
-                eprintln!("Error parsing color: {}", e);
-                std::process::exit(1);
-            });
+        Commands::Harmonies { color, space, names, cvd } => {
+            let rgb: RGB = parse_color(&color);
+            let swatch = |color: RGB| format!("{}{}", display_swatch(color, names), cvd.swatches(color));
 
-            println!("\nColor Harmonies for Input: {}", rgb.display_with_color());
-            println!("Complement: {}", rgb.complement().display_with_color());
+            println!("\nColor Harmonies for Input: {}", swatch(rgb));
+            println!("Complement: {}", swatch(rgb.complement()));
             
             println!("\nTriads:");
-            for color in rgb.triads() {
-                println!("  {}", color.display_with_color());
+            for color in rgb.triads_in(space) {
+                println!("  {}", swatch(color));
             }
 
             println!("\nTetrads:");
-            for color in rgb.tetrads() {
-                println!("  {}", color.display_with_color());
+            for color in rgb.tetrads_in(space) {
+                println!("  {}", swatch(color));
             }
         },
-        Commands::Convert { color, format } => {
-            let rgb = RGB::from_str(&color).unwrap_or_else(|e| {
-                eprintln!("Error parsing color: {}", e);
-                std::process::exit(1);
-            });
-
-            match format.to_lowercase().as_str() {
-                "hex" => println!("{}", rgb.display_with_color()),
-                "rgb" => println!("{} RGB({}, {}, {})", 
-                    rgb.to_ansi_color_block(), rgb.r, rgb.g, rgb.b),
+        Commands::Convert { color, format, white, black_generation, ucr, names, cvd } => {
+            let rgba: RGBA = parse_color(&color);
+            let rgb = rgba.rgb();
+            let alpha = alpha_suffix(rgba);
+
+            let text = match format.to_lowercase().as_str() {
+                "hex" => rgba.to_hex(),
+                "rgb" => format!("RGB({}, {}, {}{})", rgb.r, rgb.g, rgb.b, alpha),
                 "hsl" => {
                     let hsl = rgb.to_hsl();
-                    println!("{} HSL({:.1}, {:.1}%, {:.1}%)", 
-                        rgb.to_ansi_color_block(), hsl.h, hsl.s, hsl.l);
+                    format!("HSL({:.1}, {:.1}%, {:.1}%{})", hsl.h, hsl.s, hsl.l, alpha)
+                },
+                "hsv" => {
+                    let hsv = rgb.to_hsv();
+                    format!("HSV({:.1}, {:.1}%, {:.1}%{})", hsv.h, hsv.s, hsv.v, alpha)
+                },
+                "hwb" => {
+                    let hwb = rgb.to_hwb();
+                    format!("HWB({:.1}, {:.1}%, {:.1}%{})", hwb.h, hwb.w, hwb.b, alpha)
+                },
+                "linear" => {
+                    let linear = rgb.to_linear();
+                    format!("linear({:.4}, {:.4}, {:.4}{})", linear.r, linear.g, linear.b, alpha)
+                },
+                "xyz" => {
+                    let xyz = rgb.to_xyz();
+                    format!("XYZ({:.4}, {:.4}, {:.4}{})", xyz.x, xyz.y, xyz.z, alpha)
+                },
+                "lab" => {
+                    let lab = rgb.to_lab_with(white);
+                    format!("Lab({:.2}, {:.2}, {:.2}{})", lab.l, lab.a, lab.b, alpha)
+                },
+                "lch" => {
+                    let lch = rgb.to_lab_with(white).to_lch();
+                    format!("LCh({:.2}, {:.2}, {:.1}{})", lch.l, lch.c, lch.h, alpha)
+                },
+                "oklab" => {
+                    let lab = rgb.to_oklab();
+                    format!("oklab({:.4}, {:.4}, {:.4}{})", lab.l, lab.a, lab.b, alpha)
+                },
+                "oklch" => {
+                    let lch = rgb.to_oklch();
+                    format!("oklch({:.4}, {:.4}, {:.1}{})", lch.l, lch.c, lch.h, alpha)
+                },
+                "cmyk" => {
+                    let separation = NaiveCmyk::new(black_generation / 100.0, ucr / 100.0);
+                    let cmyk = rgb.to_cmyk_with(&separation);
+                    format!("CMYK({:.1}%, {:.1}%, {:.1}%, {:.1}%{})",
+                        cmyk.c, cmyk.m, cmyk.y, cmyk.k, alpha)
+                },
+                "name" => {
+                    let named = rgb.nearest_name(names.unwrap_or_default());
+                    let hex = RGBA::new(named.rgb.r, named.rgb.g, named.rgb.b, rgba.a).to_hex();
+                    format!("{} {}", named, hex)
                 },
                 _ => unreachable!(), // clap validates the format for us
+            };
+
+            match names {
+                Some(table) if format != "name" => println!("{} {}  {}{}",
+                    rgb.to_ansi_color_block(), text, rgb.nearest_name(table), cvd.swatches(rgb)),
+                _ => println!("{} {}{}", rgb.to_ansi_color_block(), text, cvd.swatches(rgb)),
             }
         },
+        Commands::Colormap { action: Some(action), .. } => match action {
+            ColormapCommand::Export { name, format, steps, output, shape } => {
+                let colormap = load_colormap(&name, &shape);
+                export_colormap(&colormap, format, steps, output.as_deref());
+            },
+            ColormapCommand::Sequential { colors, space, output } => {
+                let anchors: Vec<RGB> = colors.iter().map(|c| parse_color(c)).collect();
+                output.show(Colormap::sequential(output.name.clone(), &anchors, space, 256));
+            },
+            ColormapCommand::Diverging { from, to, neutral, space, hue_space, output } => {
+                let from: RGB = parse_color(&from);
+                let to = parse_target(from, &to, hue_space);
+                output.show(Colormap::diverging(output.name.clone(), from, to, neutral, space, 256));
+            },
+            ColormapCommand::Cyclic { colors, four_phase, lightness, space, output } => {
+                let name = output.name.clone();
+                let colormap = if four_phase {
+                    Colormap::cyclic(name, &FOUR_PHASE, space, 256)
+                } else if colors.is_empty() {
+                    Colormap::hue_circle(name, lightness / 100.0, space, 256)
+                } else {
+                    let anchors: Vec<RGB> = colors.iter().map(|c| parse_color(c)).collect();
+                    Colormap::cyclic(name, &anchors, space, 256)
+                };
+                output.show(colormap);
+            },
+            ColormapCommand::Analyze { name, samples, tolerance, json, strict, shape } => {
+                let colormap = load_colormap(&name, &shape);
+                let report = colormap.analyze(samples, tolerance / 100.0);
+                if json {
+                    print!("{}", report.to_json());
+                } else {
+                    println!("{}", colormap.preview(64));
+                    print!("{}", report.to_text(11));
+                }
+                if strict && !report.passed() {
+                    std::process::exit(2);
+                }
+            },
+        },
+        Commands::Colormap {
+            action: None, name, steps, annotate_text_colors, text_color, contrast, shape,
+        } => match name {
+            Some(name) if annotate_text_colors => {
+                let colormap = load_colormap(&name, &shape);
+                let candidates: Vec<RGB> = if text_color.is_empty() {
+                    vec![RGB::new(0, 0, 0), RGB::new(255, 255, 255)]
+                } else {
+                    text_color.iter().map(|c| parse_color(c)).collect()
+                };
+                let steps = steps.unwrap_or(11);
+                let text_colors = colormap.text_colors(steps, &candidates, contrast);
+
+                println!("{} ({})", colormap.name, colormap.kind);
+                println!("{}", colormap.preview(64));
+                for (background, text) in colormap.discretize(steps).into_iter().zip(text_colors) {
+                    let score = match contrast {
+                        ContrastMethod::Wcag => format!("{:.2}:1", text.contrast_ratio(background)),
+                        ContrastMethod::Apca => format!("Lc {:.1}", text.apca_contrast(background)),
+                    };
+                    println!("{} {}  text {}  {}", text.to_ansi_text_on(background, "  Aa  "),
+                        background.to_hex(), text.to_hex(), score);
+                }
+            },
+            Some(name) => preview_colormap(&load_colormap(&name, &shape), steps),
+            None => {
+                for colormap in Colormap::builtins() {
+                    println!("{:<10} {:<11} {}", colormap.name, colormap.kind, colormap.preview(48));
+                }
+            },
+        },
+        Commands::Diff { first, second, cmc: (l, c) } => {
+            let a: RGB = parse_color(&first);
+            let b: RGB = parse_color(&second);
+            let de2000 = a.delta_e_2000(b);
+
+            println!("{}", a.display_with_color());
+            println!("{}\n", b.display_with_color());
+            println!("{:<17}{:.2}", "ΔE76:", a.delta_e_76(b));
+            println!("{:<17}{:.2}", "ΔE94:", a.delta_e_94(b));
+            println!("{:<17}{:.2}", "ΔE2000:", de2000);
+            println!("{:<17}{:.2}", format!("ΔE CMC {}:{}:", l, c), a.delta_e_cmc(b, l, c));
+            println!("\n{}", verdict(de2000));
+        },
+        Commands::Contrast { text, background, sample } => {
+            let background: RGB = parse_color(&background);
+            let text = parse_color::<RGBA>(&text).over(background);
+            let ratio = text.contrast_ratio(background);
+            let lc = text.apca_contrast(background);
+
+            println!("Text:        {}", text.display_with_color());
+            println!("Background:  {}", background.display_with_color());
+
+            println!("\nWCAG 2 contrast ratio: {:.2}:1", ratio);
+            for level in [WcagLevel::AA, WcagLevel::AAA] {
+                for (large, label) in [(false, "normal text"), (true, "large text")] {
+                    let min = level.min_ratio(large);
+                    let result = if ratio >= min { "pass" } else { "fail" };
+                    println!("  {:<3} {:<11} {:>4}:1  {}", level, label, min, result);
+                }
+            }
+
+            println!("\nAPCA Lc: {:.1} ({})", lc, apca_guidance(lc));
+
+            let padded = format!("  {}  ", sample);
+            let blank = " ".repeat(padded.chars().count());
+            println!("\n{}", text.to_ansi_text_on(background, &blank));
+            println!("{}", text.to_ansi_text_on(background, &padded));
+            println!("{}", text.to_ansi_text_on(background, &blank));
+        },
+        Commands::Palette { action: Some(action), .. } => match action {
+            PaletteCommand::Export { name, format, count, output } => {
+                let palette = load_palette(&name, count);
+                write_output(&palette.export(format), output.as_deref());
+            },
+            PaletteCommand::Distinct {
+                count, lightness, chroma, exclude_hue, include, seed, name, format, output, names,
+            } => {
+                let options = DistinctOptions {
+                    lightness,
+                    chroma,
+                    exclude_hues: exclude_hue,
+                    include: include.iter().map(|c| parse_color(c)).collect(),
+                    seed,
+                };
+                let palette = Palette::distinct(name, count, &options).unwrap_or_else(|e| {
+                    eprintln!("Error: {}", e);
+                    std::process::exit(1);
+                });
+
+                if let Some(format) = format {
+                    write_output(&palette.export(format), output.as_deref());
+                    return;
+                }
+                for &color in &palette.colors {
+                    println!("{}", display_swatch(color, names));
+                }
+                if let Some((i, j, distance)) = palette.closest_pair() {
+                    println!("\nMinimum ΔE2000: {:.1} ({} and {})",
+                        distance, palette.colors[i].to_hex(), palette.colors[j].to_hex());
+                }
+            },
+        },
+        Commands::CvdCheck {
+            name, count, cvd, severity, cvd_model, threshold, json, shape,
+        } => {
+            let simulators: Vec<CvdSimulator> = cvd.iter()
+                .map(|&deficiency| {
+                    CvdSimulator::new(deficiency).with_severity(severity).with_model(cvd_model)
+                })
+                .collect();
+            let report = match Palette::builtin(&name) {
+                Some(_) if shape.is_set() => {
+                    eprintln!("Error: '{}' is a palette; colormap options do not apply to it", name);
+                    std::process::exit(1);
+                },
+                Some(_) => load_palette(&name, count).cvd_report(&simulators, threshold),
+                None => load_colormap(&name, &shape).cvd_report(count.unwrap_or(7), &simulators, threshold),
+            };
+
+            if json {
+                print!("{}", report.to_json());
+            } else {
+                print_cvd_strips(&report);
+                print!("{}", report.to_text());
+            }
+            if !report.passed() {
+                std::process::exit(2);
+            }
+        },
+        Commands::Palette { action: None, name, count, names } => match name {
+            Some(name) => {
+                let palette = load_palette(&name, count);
+
+                println!("{} ({} colors)", palette.name, palette.colors.len());
+                for color in palette.colors {
+                    println!("{}", display_swatch(color, names));
+                }
+            },
+            None => {
+                for palette in Palette::builtins() {
+                    println!("{:<18} {:>2}  {}", palette.name, palette.colors.len(), palette.preview(2));
+                }
+            },
+        },
     }
 }
@@ -0,0 +1,194 @@
+//! Built-in color name tables and nearest-name lookup.
+//!
+//! Names can be qualified with a table prefix (`css:`, `x11:`, `xkcd:`).
+//! Unqualified names are looked up in the CSS, X11 and xkcd tables in that
+//! order. Matching ignores case, whitespace, `-` and `_`, so `Steel Blue`
+//! finds `steelblue` and `xkcd:dusty-rose` finds `dusty rose`.
+
+mod css;
+mod x11;
+mod xkcd;
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::RGB;
+
+/// One of the built-in color name tables.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NameTable {
+    /// The 148 CSS Color 4 keywords.
+    #[default]
+    Css,
+    /// The X11 `rgb.txt` names, where they differ from CSS.
+    X11,
+    /// Common names from the xkcd color survey.
+    Xkcd,
+}
+
+impl NameTable {
+    /// The prefix that selects this table in qualified names.
+    pub fn prefix(self) -> &'static str {
+        match self {
+            NameTable::Css => "css",
+            NameTable::X11 => "x11",
+            NameTable::Xkcd => "xkcd",
+        }
+    }
+
+    /// Iterates over every `(name, color)` entry of the table.
+    pub fn entries(self) -> Box<dyn Iterator<Item = (&'static str, RGB)>> {
+        match self {
+            NameTable::Css => Box::new(css::CSS.iter().copied()),
+            NameTable::X11 => Box::new(
+                x11::X11.iter().copied().chain(
+                    css::CSS.iter().copied().filter(|(name, _)| {
+                        *name != "rebeccapurple" && !x11::X11.iter().any(|(x, _)| x == name)
+                    }),
+                ),
+            ),
+            NameTable::Xkcd => Box::new(xkcd::XKCD.iter().copied()),
+        }
+    }
+
+    /// Looks up a name in this table only.
+    pub fn lookup(self, name: &str) -> Option<RGB> {
+        let key = normalize(name);
+        if self == NameTable::X11 {
+            if let Some(rgb) = x11::gray_ramp(&key) {
+                return Some(rgb);
+            }
+        }
+        self.entries()
+            .find(|(entry, _)| normalize(entry) == key)
+            .map(|(_, rgb)| rgb)
+    }
+
+    /// Finds the entry closest to `rgb` by Oklab distance.
+    pub fn nearest(self, rgb: RGB) -> NamedColor {
+        let target = rgb.to_oklab();
+        self.entries()
+            .map(|(name, entry)| {
+                let lab = entry.to_oklab();
+                let distance = ((lab.l - target.l).powi(2)
+                    + (lab.a - target.a).powi(2)
+                    + (lab.b - target.b).powi(2))
+                .sqrt();
+                NamedColor { name, table: self, rgb: entry, distance }
+            })
+            .min_by(|a, b| a.distance.total_cmp(&b.distance))
+            .expect("name tables are not empty")
+    }
+}
+
+impl FromStr for NameTable {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "css" => Ok(NameTable::Css),
+            "x11" => Ok(NameTable::X11),
+            "xkcd" => Ok(NameTable::Xkcd),
+            _ => Err(format!("Unknown name table '{}'", s)),
+        }
+    }
+}
+
+/// A named color from one of the built-in tables.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NamedColor {
+    pub name: &'static str,
+    pub table: NameTable,
+    pub rgb: RGB,
+    /// Oklab distance from the color that was looked up; zero for exact matches.
+    pub distance: f64,
+}
+
+impl NamedColor {
+    /// Returns true if the name denotes exactly the color that was looked up.
+    pub fn is_exact(&self) -> bool {
+        self.distance < 1e-9
+    }
+}
+
+impl fmt::Display for NamedColor {
+    /// Formats as `name` for CSS colors and `table:name` otherwise, prefixed
+    /// with `~` when the match is approximate.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if !self.is_exact() {
+            write!(f, "~")?;
+        }
+        match self.table {
+            NameTable::Css => write!(f, "{}", self.name),
+            table => write!(f, "{}:{}", table.prefix(), self.name),
+        }
+    }
+}
+
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| !c.is_whitespace() && *c != '-' && *c != '_')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Looks up a possibly table-qualified color name such as `rebeccapurple`
+/// or `xkcd:dusty rose`.
+pub fn lookup(name: &str) -> Option<RGB> {
+    let name = name.trim();
+    if let Some((prefix, rest)) = name.split_once(':') {
+        return prefix.parse::<NameTable>().ok()?.lookup(rest);
+    }
+    [NameTable::Css, NameTable::X11, NameTable::Xkcd]
+        .into_iter()
+        .find_map(|table| table.lookup(name))
+}
+
+impl RGB {
+    /// Returns the closest named color in the given table.
+    pub fn nearest_name(self, table: NameTable) -> NamedColor {
+        table.nearest(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn css_table_is_complete() {
+        assert_eq!(NameTable::Css.entries().count(), 148);
+        assert!(NameTable::X11.lookup("rebeccapurple").is_none());
+    }
+
+    #[test]
+    fn looks_up_exact_names() {
+        let steelblue = Some(RGB::new(0x46, 0x82, 0xb4));
+        assert_eq!(lookup("steelblue"), steelblue);
+        assert_eq!(lookup(" Steel Blue "), steelblue);
+        assert_eq!(lookup("css:steel_blue"), steelblue);
+        assert_eq!(lookup("gray"), Some(RGB::new(128, 128, 128)));
+        assert_eq!(lookup("x11:gray"), Some(RGB::new(190, 190, 190)));
+        assert_eq!(lookup("x11:gray50"), Some(RGB::new(127, 127, 127)));
+        assert_eq!(lookup("x11:steelblue"), steelblue);
+        assert_eq!(lookup("xkcd:Light-Blue"), Some(RGB::new(0x95, 0xd0, 0xfc)));
+        assert_eq!(lookup("notacolor"), None);
+        assert_eq!(lookup("pantone:red"), None);
+    }
+
+    #[test]
+    fn nearest_name_marks_approximate_matches() {
+        let exact = RGB::new(0x46, 0x82, 0xb4).nearest_name(NameTable::Css);
+        assert_eq!(exact.name, "steelblue");
+        assert!(exact.is_exact());
+        assert_eq!(exact.to_string(), "steelblue");
+
+        let near = RGB::new(0x48, 0x80, 0xb0).nearest_name(NameTable::Css);
+        assert_eq!(near.name, "steelblue");
+        assert!(!near.is_exact());
+        assert_eq!(near.to_string(), "~steelblue");
+
+        let purple = RGB::new(0x7e, 0x1e, 0x9c).nearest_name(NameTable::Xkcd);
+        assert_eq!(purple.to_string(), "xkcd:purple");
+    }
+}
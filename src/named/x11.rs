@@ -0,0 +1,42 @@
+//! X11 `rgb.txt` colors that are absent from, or differ from, the CSS table.
+//!
+//! Lookups in the X11 table fall back to the CSS colors, which were derived
+//! from X11 in the first place. The numbered `grayN`/`greyN` ramp is
+//! computed rather than tabulated.
+
+use crate::RGB;
+
+pub(crate) const X11: &[(&str, RGB)] = &[
+    ("gray", RGB { r: 190, g: 190, b: 190 }),
+    ("grey", RGB { r: 190, g: 190, b: 190 }),
+    ("green", RGB { r: 0, g: 255, b: 0 }),
+    ("maroon", RGB { r: 176, g: 48, b: 96 }),
+    ("purple", RGB { r: 160, g: 32, b: 240 }),
+    ("lightgoldenrod", RGB { r: 238, g: 221, b: 130 }),
+    ("lightslateblue", RGB { r: 132, g: 112, b: 255 }),
+    ("navyblue", RGB { r: 0, g: 0, b: 128 }),
+    ("violetred", RGB { r: 208, g: 32, b: 144 }),
+    ("webgray", RGB { r: 128, g: 128, b: 128 }),
+    ("webgrey", RGB { r: 128, g: 128, b: 128 }),
+    ("webgreen", RGB { r: 0, g: 128, b: 0 }),
+    ("webmaroon", RGB { r: 128, g: 0, b: 0 }),
+    ("webpurple", RGB { r: 128, g: 0, b: 128 }),
+    ("x11gray", RGB { r: 190, g: 190, b: 190 }),
+    ("x11grey", RGB { r: 190, g: 190, b: 190 }),
+    ("x11green", RGB { r: 0, g: 255, b: 0 }),
+    ("x11maroon", RGB { r: 176, g: 48, b: 96 }),
+    ("x11purple", RGB { r: 160, g: 32, b: 240 }),
+];
+
+/// Resolves the X11 `gray0`..`gray100` (and `grey`) ramp.
+pub(crate) fn gray_ramp(name: &str) -> Option<RGB> {
+    let n: u32 = name.strip_prefix("gray")
+        .or_else(|| name.strip_prefix("grey"))?
+        .parse()
+        .ok()?;
+    if n > 100 {
+        return None;
+    }
+    let v = (n as f64 * 2.55).round() as u8;
+    Some(RGB { r: v, g: v, b: v })
+}
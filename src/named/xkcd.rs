@@ -0,0 +1,56 @@
+//! The most frequently named colors of the xkcd color survey.
+
+use crate::RGB;
+
+pub(crate) const XKCD: &[(&str, RGB)] = &[
+    ("purple", RGB { r: 0x7e, g: 0x1e, b: 0x9c }),
+    ("green", RGB { r: 0x15, g: 0xb0, b: 0x1a }),
+    ("blue", RGB { r: 0x03, g: 0x43, b: 0xdf }),
+    ("pink", RGB { r: 0xff, g: 0x81, b: 0xc0 }),
+    ("brown", RGB { r: 0x65, g: 0x37, b: 0x00 }),
+    ("red", RGB { r: 0xe5, g: 0x00, b: 0x00 }),
+    ("light blue", RGB { r: 0x95, g: 0xd0, b: 0xfc }),
+    ("teal", RGB { r: 0x02, g: 0x93, b: 0x86 }),
+    ("orange", RGB { r: 0xf9, g: 0x73, b: 0x06 }),
+    ("light green", RGB { r: 0x96, g: 0xf9, b: 0x7b }),
+    ("magenta", RGB { r: 0xc2, g: 0x00, b: 0x78 }),
+    ("yellow", RGB { r: 0xff, g: 0xff, b: 0x14 }),
+    ("sky blue", RGB { r: 0x75, g: 0xbb, b: 0xfd }),
+    ("grey", RGB { r: 0x92, g: 0x95, b: 0x91 }),
+    ("lime green", RGB { r: 0x89, g: 0xfe, b: 0x05 }),
+    ("light purple", RGB { r: 0xbf, g: 0x77, b: 0xf6 }),
+    ("violet", RGB { r: 0x9a, g: 0x0e, b: 0xea }),
+    ("dark green", RGB { r: 0x03, g: 0x35, b: 0x00 }),
+    ("turquoise", RGB { r: 0x06, g: 0xc2, b: 0xac }),
+    ("lavender", RGB { r: 0xc7, g: 0x9f, b: 0xef }),
+    ("dark blue", RGB { r: 0x00, g: 0x03, b: 0x5b }),
+    ("tan", RGB { r: 0xd1, g: 0xb2, b: 0x6f }),
+    ("cyan", RGB { r: 0x00, g: 0xff, b: 0xff }),
+    ("aqua", RGB { r: 0x13, g: 0xea, b: 0xc9 }),
+    ("forest green", RGB { r: 0x06, g: 0x47, b: 0x0c }),
+    ("mauve", RGB { r: 0xae, g: 0x71, b: 0x81 }),
+    ("dark purple", RGB { r: 0x35, g: 0x06, b: 0x3e }),
+    ("bright green", RGB { r: 0x01, g: 0xff, b: 0x07 }),
+    ("maroon", RGB { r: 0x65, g: 0x00, b: 0x21 }),
+    ("olive", RGB { r: 0x6e, g: 0x75, b: 0x0e }),
+    ("salmon", RGB { r: 0xff, g: 0x79, b: 0x6c }),
+    ("beige", RGB { r: 0xe6, g: 0xda, b: 0xa6 }),
+    ("royal blue", RGB { r: 0x05, g: 0x04, b: 0xaa }),
+    ("navy blue", RGB { r: 0x00, g: 0x11, b: 0x46 }),
+    ("lilac", RGB { r: 0xce, g: 0xa2, b: 0xfd }),
+    ("black", RGB { r: 0x00, g: 0x00, b: 0x00 }),
+    ("hot pink", RGB { r: 0xff, g: 0x02, b: 0x8d }),
+    ("light brown", RGB { r: 0xad, g: 0x81, b: 0x50 }),
+    ("pale green", RGB { r: 0xc7, g: 0xfd, b: 0xb5 }),
+    ("peach", RGB { r: 0xff, g: 0xb0, b: 0x7c }),
+    ("olive green", RGB { r: 0x67, g: 0x7a, b: 0x04 }),
+    ("dark pink", RGB { r: 0xcb, g: 0x41, b: 0x6b }),
+    ("periwinkle", RGB { r: 0x8e, g: 0x82, b: 0xfe }),
+    ("sea green", RGB { r: 0x53, g: 0xfc, b: 0xa1 }),
+    ("lime", RGB { r: 0xaa, g: 0xff, b: 0x32 }),
+    ("indigo", RGB { r: 0x38, g: 0x02, b: 0x82 }),
+    ("mustard", RGB { r: 0xce, g: 0xb3, b: 0x01 }),
+    ("light pink", RGB { r: 0xff, g: 0xd1, b: 0xdf }),
+    ("dusty rose", RGB { r: 0xc0, g: 0x73, b: 0x7a }),
+    ("white", RGB { r: 0xff, g: 0xff, b: 0xff }),
+];
@@ -0,0 +1,255 @@
+use std::str::FromStr;
+
+use crate::css::Function;
+use crate::ParseColorError;
+use crate::{LinearRGB, RGB};
+
+/// A color in Björn Ottosson's Oklab perceptual space.
+///
+/// `l` is lightness in `[0, 1]`; `a` and `b` are opponent axes, roughly
+/// within `[-0.4, 0.4]` for displayable colors.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Oklab {
+    pub l: f64,
+    pub a: f64,
+    pub b: f64,
+}
+
+/// A color in Oklch, the cylindrical form of [`Oklab`].
+///
+/// `c` is chroma and `h` is the hue angle in degrees `[0, 360)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Oklch {
+    pub l: f64,
+    pub c: f64,
+    pub h: f64,
+}
+
+impl Oklab {
+    /// Creates a new Oklab color.
+    pub fn new(l: f64, a: f64, b: f64) -> Self {
+        Oklab { l, a, b }
+    }
+
+    /// Converts from linear-light sRGB.
+    pub fn from_linear(rgb: LinearRGB) -> Self {
+        let l = 0.4122214708 * rgb.r + 0.5363325363 * rgb.g + 0.0514459929 * rgb.b;
+        let m = 0.2119034982 * rgb.r + 0.6806995451 * rgb.g + 0.1073969566 * rgb.b;
+        let s = 0.0883024619 * rgb.r + 0.2817188376 * rgb.g + 0.6299787005 * rgb.b;
+
+        let l = l.cbrt();
+        let m = m.cbrt();
+        let s = s.cbrt();
+
+        Oklab {
+            l: 0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+            a: 1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+            b: 0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+        }
+    }
+
+    /// Converts to linear-light sRGB without clipping.
+    pub fn to_linear(self) -> LinearRGB {
+        let l = self.l + 0.3963377774 * self.a + 0.2158037573 * self.b;
+        let m = self.l - 0.1055613458 * self.a - 0.0638541728 * self.b;
+        let s = self.l - 0.0894841775 * self.a - 1.2914855480 * self.b;
+
+        let l = l * l * l;
+        let m = m * m * m;
+        let s = s * s * s;
+
+        LinearRGB {
+            r: 4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s,
+            g: -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s,
+            b: -0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s,
+        }
+    }
+
+    /// Converts to 8-bit sRGB, clipping out-of-gamut values.
+    pub fn to_rgb(self) -> RGB {
+        self.to_linear().to_rgb()
+    }
+
+    /// Converts to the cylindrical Oklch form.
+    pub fn to_oklch(self) -> Oklch {
+        let c = self.a.hypot(self.b);
+        let h = self.b.atan2(self.a).to_degrees().rem_euclid(360.0);
+        Oklch { l: self.l, c, h }
+    }
+}
+
+impl Oklch {
+    /// Creates a new Oklch color.
+    pub fn new(l: f64, c: f64, h: f64) -> Self {
+        Oklch { l, c, h }
+    }
+
+    /// Converts to the rectangular Oklab form.
+    pub fn to_oklab(self) -> Oklab {
+        let (sin, cos) = self.h.to_radians().sin_cos();
+        Oklab {
+            l: self.l,
+            a: self.c * cos,
+            b: self.c * sin,
+        }
+    }
+
+    /// Converts to 8-bit sRGB, clipping out-of-gamut values.
+    pub fn to_rgb(self) -> RGB {
+        self.to_oklab().to_rgb()
+    }
+
+    /// Converts to 8-bit sRGB, reducing chroma at constant lightness and hue
+    /// until the color fits in the sRGB gamut.
+    pub fn to_rgb_in_gamut(self) -> RGB {
+        let lch = Oklch { l: self.l.clamp(0.0, 1.0), ..self };
+        let c = max_chroma(lch.c, |c| fits(Oklch { c, ..lch }.to_oklab().to_linear()));
+        Oklch { c, ..lch }.to_rgb()
+    }
+}
+
+/// Returns true if `rgb` lies in the sRGB gamut.
+pub(crate) fn fits(rgb: LinearRGB) -> bool {
+    // Allow for rounding error so exact sRGB colors are not desaturated.
+    [rgb.r, rgb.g, rgb.b].iter().all(|c| (-1e-6..=1.0 + 1e-6).contains(c))
+}
+
+/// Returns the largest chroma up to `max` for which `fits` holds, assuming
+/// that every lower chroma fits as well.
+pub(crate) fn max_chroma(max: f64, fits: impl Fn(f64) -> bool) -> f64 {
+    if fits(max) {
+        return max;
+    }
+
+    let mut lo = 0.0;
+    let mut hi = max;
+    for _ in 0..24 {
+        let mid = (lo + hi) / 2.0;
+        if fits(mid) {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+impl RGB {
+    /// Converts to Oklab.
+    pub fn to_oklab(self) -> Oklab {
+        Oklab::from_linear(self.to_linear())
+    }
+
+    /// Converts to Oklch.
+    pub fn to_oklch(self) -> Oklch {
+        self.to_oklab().to_oklch()
+    }
+}
+
+impl From<Oklab> for Oklch {
+    fn from(lab: Oklab) -> Self {
+        lab.to_oklch()
+    }
+}
+
+impl From<Oklch> for Oklab {
+    fn from(lch: Oklch) -> Self {
+        lch.to_oklab()
+    }
+}
+
+impl From<RGB> for Oklab {
+    fn from(rgb: RGB) -> Self {
+        rgb.to_oklab()
+    }
+}
+
+impl From<Oklab> for RGB {
+    fn from(lab: Oklab) -> Self {
+        lab.to_rgb()
+    }
+}
+
+impl From<RGB> for Oklch {
+    fn from(rgb: RGB) -> Self {
+        rgb.to_oklch()
+    }
+}
+
+impl From<Oklch> for RGB {
+    fn from(lch: Oklch) -> Self {
+        lch.to_rgb()
+    }
+}
+
+impl FromStr for Oklab {
+    type Err = ParseColorError;
+
+    /// Parses CSS `oklab(L a b)`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Function::parse(s)?.oklab()
+    }
+}
+
+impl FromStr for Oklch {
+    type Err = ParseColorError;
+
+    /// Parses CSS `oklch(L C h)`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Function::parse(s)?.oklch()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::XYZ;
+
+    fn assert_oklab(actual: Oklab, expected: [f64; 3], tolerance: f64) {
+        let close = [actual.l, actual.a, actual.b].into_iter().zip(expected).all(|(a, e)| (a - e).abs() < tolerance);
+        assert!(close, "{:?} != {:?}", actual, expected);
+    }
+
+    #[test]
+    fn matches_ottosson_reference_pairs() {
+        // From "A perceptual color space for image processing" (Ottosson, 2020)
+        let cases = [
+            ([0.950, 1.000, 1.089], [1.000, 0.000, 0.000]),
+            ([1.000, 0.000, 0.000], [0.450, 1.236, -0.019]),
+            ([0.000, 1.000, 0.000], [0.922, -0.671, 0.263]),
+            ([0.000, 0.000, 1.000], [0.153, -1.415, -0.449]),
+        ];
+        for ([x, y, z], expected) in cases {
+            assert_oklab(Oklab::from_linear(XYZ::new(x, y, z).to_linear()), expected, 2e-3);
+        }
+    }
+
+    #[test]
+    fn matches_reference_srgb_values() {
+        assert_oklab(RGB::new(255, 0, 0).to_oklab(), [0.62796, 0.22486, 0.12585], 1e-4);
+        assert_oklab(RGB::new(255, 255, 255).to_oklab(), [1.0, 0.0, 0.0], 1e-4);
+        assert_oklab(RGB::new(0, 0, 0).to_oklab(), [0.0, 0.0, 0.0], 1e-9);
+    }
+
+    #[test]
+    fn oklab_and_oklch_round_trip() {
+        for v in (0..=255).step_by(17) {
+            for rgb in [RGB::new(v, 0, 255 - v), RGB::new(255, v, v / 3), RGB::new(v, v, v)] {
+                assert_eq!(rgb.to_oklab().to_rgb(), rgb);
+                assert_eq!(rgb.to_oklch().to_rgb(), rgb);
+            }
+        }
+    }
+
+    #[test]
+    fn gamut_mapping_keeps_lightness_and_hue() {
+        let lch = Oklch::new(0.7, 0.4, 150.0);
+        assert!(!fits(lch.to_oklab().to_linear()));
+        let mapped = lch.to_rgb_in_gamut().to_oklch();
+        assert!((mapped.l - lch.l).abs() < 0.01 && (mapped.h - lch.h).abs() < 1.0, "{:?}", mapped);
+        assert!(mapped.c < lch.c);
+
+        let red = RGB::new(255, 0, 0);
+        assert_eq!(red.to_oklch().to_rgb_in_gamut(), red);
+    }
+}
@@ -0,0 +1,140 @@
+//! Colors of the built-in qualitative palettes, in their published order.
+
+use crate::RGB;
+
+pub(crate) struct Builtin {
+    pub name: &'static str,
+    pub colors: &'static [RGB],
+}
+
+const fn hex(v: u32) -> RGB {
+    RGB::new((v >> 16) as u8, (v >> 8) as u8, v as u8)
+}
+
+pub(crate) const BUILTINS: &[Builtin] = &[
+    // Okabe & Ito, "Color Universal Design" (2008)
+    Builtin {
+        name: "okabe-ito",
+        colors: &[
+            hex(0x000000), hex(0xE69F00), hex(0x56B4E9), hex(0x009E73), hex(0xF0E442),
+            hex(0x0072B2), hex(0xD55E00), hex(0xCC79A7), hex(0x999999),
+        ],
+    },
+    // Paul Tol's qualitative schemes
+    Builtin {
+        name: "tol-bright",
+        colors: &[
+            hex(0x4477AA), hex(0xEE6677), hex(0x228833), hex(0xCCBB44), hex(0x66CCEE),
+            hex(0xAA3377), hex(0xBBBBBB),
+        ],
+    },
+    Builtin {
+        name: "tol-vibrant",
+        colors: &[
+            hex(0xEE7733), hex(0x0077BB), hex(0x33BBEE), hex(0xEE3377), hex(0xCC3311),
+            hex(0x009988), hex(0xBBBBBB),
+        ],
+    },
+    Builtin {
+        name: "tol-muted",
+        colors: &[
+            hex(0xCC6677), hex(0x332288), hex(0xDDCC77), hex(0x117733), hex(0x88CCEE),
+            hex(0x882255), hex(0x44AA99), hex(0x999933), hex(0xAA4499),
+        ],
+    },
+    Builtin {
+        name: "tol-high-contrast",
+        colors: &[hex(0x004488), hex(0xDDAA33), hex(0xBB5566)],
+    },
+    Builtin {
+        name: "tol-light",
+        colors: &[
+            hex(0x77AADD), hex(0xEE8866), hex(0xEEDD88), hex(0xFFAABB), hex(0x99DDFF),
+            hex(0x44BB99), hex(0xBBCC33), hex(0xAAAA00), hex(0xDDDDDD),
+        ],
+    },
+    // ColorBrewer qualitative schemes by Cynthia Brewer
+    Builtin {
+        name: "set1",
+        colors: &[
+            hex(0xE41A1C), hex(0x377EB8), hex(0x4DAF4A), hex(0x984EA3), hex(0xFF7F00),
+            hex(0xFFFF33), hex(0xA65628), hex(0xF781BF), hex(0x999999),
+        ],
+    },
+    Builtin {
+        name: "set2",
+        colors: &[
+            hex(0x66C2A5), hex(0xFC8D62), hex(0x8DA0CB), hex(0xE78AC3), hex(0xA6D854),
+            hex(0xFFD92F), hex(0xE5C494), hex(0xB3B3B3),
+        ],
+    },
+    Builtin {
+        name: "set3",
+        colors: &[
+            hex(0x8DD3C7), hex(0xFFFFB3), hex(0xBEBADA), hex(0xFB8072), hex(0x80B1D3),
+            hex(0xFDB462), hex(0xB3DE69), hex(0xFCCDE5), hex(0xD9D9D9), hex(0xBC80BD),
+            hex(0xCCEBC5), hex(0xFFED6F),
+        ],
+    },
+    Builtin {
+        name: "dark2",
+        colors: &[
+            hex(0x1B9E77), hex(0xD95F02), hex(0x7570B3), hex(0xE7298A), hex(0x66A61E),
+            hex(0xE6AB02), hex(0xA6761D), hex(0x666666),
+        ],
+    },
+    Builtin {
+        name: "paired",
+        colors: &[
+            hex(0xA6CEE3), hex(0x1F78B4), hex(0xB2DF8A), hex(0x33A02C), hex(0xFB9A99),
+            hex(0xE31A1C), hex(0xFDBF6F), hex(0xFF7F00), hex(0xCAB2D6), hex(0x6A3D9A),
+            hex(0xFFFF99), hex(0xB15928),
+        ],
+    },
+    Builtin {
+        name: "pastel1",
+        colors: &[
+            hex(0xFBB4AE), hex(0xB3CDE3), hex(0xCCEBC5), hex(0xDECBE4), hex(0xFED9A6),
+            hex(0xFFFFCC), hex(0xE5D8BD), hex(0xFDDAEC), hex(0xF2F2F2),
+        ],
+    },
+    Builtin {
+        name: "pastel2",
+        colors: &[
+            hex(0xB3E2CD), hex(0xFDCDAC), hex(0xCBD5E8), hex(0xF4CAE4), hex(0xE6F5C9),
+            hex(0xFFF2AE), hex(0xF1E2CC), hex(0xCCCCCC),
+        ],
+    },
+    Builtin {
+        name: "accent",
+        colors: &[
+            hex(0x7FC97F), hex(0xBEAED4), hex(0xFDC086), hex(0xFFFF99), hex(0x386CB0),
+            hex(0xF0027F), hex(0xBF5B17), hex(0x666666),
+        ],
+    },
+    // Tableau 10 (2016 redesign), and the earlier Tableau 10/20 used by
+    // matplotlib as tab10 and tab20
+    Builtin {
+        name: "tableau10",
+        colors: &[
+            hex(0x4E79A7), hex(0xF28E2B), hex(0xE15759), hex(0x76B7B2), hex(0x59A14F),
+            hex(0xEDC948), hex(0xB07AA1), hex(0xFF9DA7), hex(0x9C755F), hex(0xBAB0AC),
+        ],
+    },
+    Builtin {
+        name: "tab10",
+        colors: &[
+            hex(0x1F77B4), hex(0xFF7F0E), hex(0x2CA02C), hex(0xD62728), hex(0x9467BD),
+            hex(0x8C564B), hex(0xE377C2), hex(0x7F7F7F), hex(0xBCBD22), hex(0x17BECF),
+        ],
+    },
+    Builtin {
+        name: "tab20",
+        colors: &[
+            hex(0x1F77B4), hex(0xAEC7E8), hex(0xFF7F0E), hex(0xFFBB78), hex(0x2CA02C),
+            hex(0x98DF8A), hex(0xD62728), hex(0xFF9896), hex(0x9467BD), hex(0xC5B0D5),
+            hex(0x8C564B), hex(0xC49C94), hex(0xE377C2), hex(0xF7B6D2), hex(0x7F7F7F),
+            hex(0xC7C7C7), hex(0xBCBD22), hex(0xDBDB8D), hex(0x17BECF), hex(0x9EDAE5),
+        ],
+    },
+];
@@ -0,0 +1,226 @@
+//! Generation of maximally distinct categorical palettes.
+
+use super::Palette;
+use crate::{Lab, RGB};
+
+/// Constraints for [`Palette::distinct`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DistinctOptions {
+    /// Allowed CIELAB L\* range.
+    pub lightness: (f64, f64),
+    /// Allowed CIELAB C\*ab range.
+    pub chroma: (f64, f64),
+    /// LCh(ab) hue ranges in degrees to avoid; a range whose start is
+    /// greater than its end wraps around through 0°.
+    pub exclude_hues: Vec<(f64, f64)>,
+    /// Colors that must appear in the palette, placed first.
+    pub include: Vec<RGB>,
+    /// Seed for the candidate search; the same seed gives the same palette.
+    pub seed: u64,
+}
+
+impl Default for DistinctOptions {
+    fn default() -> Self {
+        DistinctOptions {
+            lightness: (25.0, 90.0),
+            chroma: (20.0, 150.0),
+            exclude_hues: Vec::new(),
+            include: Vec::new(),
+            seed: 0,
+        }
+    }
+}
+
+impl DistinctOptions {
+    /// Returns true if `lab` satisfies the lightness, chroma and hue constraints.
+    fn allows(&self, lab: Lab) -> bool {
+        let lch = lab.to_lch();
+        let within = |v: f64, (lo, hi): (f64, f64)| lo <= v && v <= hi;
+        let excluded = |&(start, end): &(f64, f64)| {
+            let (start, end) = (start.rem_euclid(360.0), end.rem_euclid(360.0));
+            if start <= end {
+                within(lch.h, (start, end))
+            } else {
+                lch.h >= start || lch.h <= end
+            }
+        };
+        within(lch.l, self.lightness)
+            && within(lch.c, self.chroma)
+            && !self.exclude_hues.iter().any(excluded)
+    }
+}
+
+/// The SplitMix64 generator, small and good enough for a seeded search.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+}
+
+/// Number of random sRGB candidates the palette is chosen from.
+const CANDIDATES: usize = 4000;
+
+impl Palette {
+    /// Generates `n` colors whose minimum pairwise CIEDE2000 difference is
+    /// as large as possible within `options`.
+    ///
+    /// The colors are chosen from a seeded random sample of the sRGB gamut:
+    /// a greedy farthest-point pass picks an initial set, which is then
+    /// refined by swapping out whichever color is closest to the others
+    /// while that improves the minimum difference.
+    pub fn distinct(name: impl Into<String>, n: usize, options: &DistinctOptions) -> Result<Palette, String> {
+        if options.include.len() > n {
+            return Err(format!("Cannot include {} colors in a palette of {}", options.include.len(), n));
+        }
+
+        let mut rng = SplitMix64(options.seed);
+        let mut pool = Vec::with_capacity(CANDIDATES);
+        for _ in 0..CANDIDATES * 50 {
+            if pool.len() == CANDIDATES {
+                break;
+            }
+            let v = rng.next();
+            let rgb = RGB::new(v as u8, (v >> 8) as u8, (v >> 16) as u8);
+            let lab = rgb.to_lab();
+            if options.allows(lab) {
+                pool.push((rgb, lab));
+            }
+        }
+        if pool.len() < n - options.include.len() {
+            return Err("Too few sRGB colors satisfy the constraints".to_string());
+        }
+
+        let mut chosen: Vec<(RGB, Lab)> = options.include.iter().map(|&rgb| (rgb, rgb.to_lab())).collect();
+        let fixed = chosen.len();
+        let min_distance = |lab: Lab, others: &[(RGB, Lab)]| {
+            others.iter().map(|o| lab.delta_e_2000(o.1)).fold(f64::INFINITY, f64::min)
+        };
+
+        // Greedy farthest-point selection
+        if chosen.is_empty() && n > 0 {
+            chosen.push(pool[rng.below(pool.len())]);
+        }
+        while chosen.len() < n {
+            let best = pool.iter()
+                .max_by(|a, b| min_distance(a.1, &chosen).total_cmp(&min_distance(b.1, &chosen)))
+                .copied()
+                .expect("the pool is not empty");
+            chosen.push(best);
+        }
+
+        // Move the free color involved in the closest pair to the candidate
+        // farthest from the rest, until that no longer helps
+        for _ in 0..n * 20 {
+            let Some((i, j, worst)) = closest_pair(&chosen) else { break };
+            let mut improved = false;
+            for k in [j, i] {
+                if k < fixed {
+                    continue;
+                }
+                let others: Vec<_> = chosen.iter().enumerate()
+                    .filter(|&(m, _)| m != k)
+                    .map(|(_, &c)| c)
+                    .collect();
+                let best = pool.iter()
+                    .max_by(|a, b| min_distance(a.1, &others).total_cmp(&min_distance(b.1, &others)))
+                    .copied()
+                    .expect("the pool is not empty");
+                if min_distance(best.1, &others) > worst + 1e-9 {
+                    chosen[k] = best;
+                    improved = true;
+                    break;
+                }
+            }
+            if !improved {
+                break;
+            }
+        }
+
+        Ok(Palette::new(name, chosen.into_iter().map(|(rgb, _)| rgb).collect()))
+    }
+
+    /// Returns the indices and CIEDE2000 difference of the two most similar
+    /// colors, or `None` if the palette has fewer than two colors.
+    pub fn closest_pair(&self) -> Option<(usize, usize, f64)> {
+        let labs: Vec<(RGB, Lab)> = self.colors.iter().map(|&rgb| (rgb, rgb.to_lab())).collect();
+        closest_pair(&labs)
+    }
+}
+
+fn closest_pair(colors: &[(RGB, Lab)]) -> Option<(usize, usize, f64)> {
+    let mut closest = None;
+    for i in 0..colors.len() {
+        for j in i + 1..colors.len() {
+            let d = colors[i].1.delta_e_2000(colors[j].1);
+            if closest.is_none_or(|(_, _, best)| d < best) {
+                closest = Some((i, j, d));
+            }
+        }
+    }
+    closest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_gives_same_palette() {
+        let options = DistinctOptions { seed: 7, ..DistinctOptions::default() };
+        let first = Palette::distinct("a", 8, &options).unwrap();
+        assert_eq!(first, Palette::distinct("a", 8, &options).unwrap());
+
+        let other = DistinctOptions { seed: 8, ..DistinctOptions::default() };
+        assert_ne!(first, Palette::distinct("a", 8, &other).unwrap());
+    }
+
+    #[test]
+    fn colors_stay_well_separated() {
+        for seed in 0..3 {
+            let options = DistinctOptions { seed, ..DistinctOptions::default() };
+            for n in [2, 8, 12] {
+                let palette = Palette::distinct("distinct", n, &options).unwrap();
+                assert_eq!(palette.colors.len(), n);
+                let (_, _, min) = palette.closest_pair().unwrap();
+                assert!(min > 25.0, "seed {}, {} colors: minimum ΔE2000 {:.1}", seed, n, min);
+            }
+        }
+    }
+
+    #[test]
+    fn respects_constraints_and_included_colors() {
+        let include = vec![RGB::new(0, 114, 178), RGB::new(213, 94, 0)];
+        let options = DistinctOptions {
+            lightness: (40.0, 80.0),
+            chroma: (30.0, 150.0),
+            exclude_hues: vec![(330.0, 30.0)],
+            include: include.clone(),
+            seed: 3,
+        };
+        let palette = Palette::distinct("constrained", 6, &options).unwrap();
+        assert_eq!(palette.colors[..2], include);
+        for &rgb in &palette.colors[2..] {
+            assert!(options.allows(rgb.to_lab()), "{} breaks the constraints", rgb.to_hex());
+        }
+        let (_, _, min) = palette.closest_pair().unwrap();
+        assert!(min > 15.0, "minimum ΔE2000 {:.1}", min);
+    }
+
+    #[test]
+    fn rejects_impossible_requests() {
+        let include = DistinctOptions { include: vec![RGB::new(0, 0, 0); 3], ..DistinctOptions::default() };
+        assert!(Palette::distinct("x", 2, &include).is_err());
+        let empty = DistinctOptions { lightness: (95.0, 99.0), chroma: (140.0, 150.0), ..DistinctOptions::default() };
+        assert!(Palette::distinct("x", 4, &empty).is_err());
+    }
+}
@@ -0,0 +1,199 @@
+//! Qualitative palettes for categorical data.
+//!
+//! Built-in palettes include Okabe-Ito, Paul Tol's schemes, the ColorBrewer
+//! qualitative sets and the Tableau palettes.
+
+mod data;
+mod generate;
+
+use std::fmt::{self, Write};
+use std::str::FromStr;
+
+use crate::colormap::export::{comment, escape_json, escape_xml, identifier, unit};
+use crate::{ExportFormat, RGB};
+
+pub use generate::DistinctOptions;
+
+/// An ordered list of distinct colors for categorical data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    pub name: String,
+    pub colors: Vec<RGB>,
+}
+
+impl Palette {
+    /// Creates a palette from its colors.
+    pub fn new(name: impl Into<String>, colors: Vec<RGB>) -> Self {
+        Palette { name: name.into(), colors }
+    }
+
+    /// Returns the palette with only its first `n` colors.
+    pub fn first(mut self, n: usize) -> Self {
+        self.colors.truncate(n);
+        self
+    }
+
+    /// Returns the built-in palette with the given (case-insensitive) name.
+    pub fn builtin(name: &str) -> Option<Palette> {
+        data::BUILTINS.iter()
+            .find(|palette| palette.name.eq_ignore_ascii_case(name.trim()))
+            .map(|palette| Palette::new(palette.name, palette.colors.to_vec()))
+    }
+
+    /// Returns the names of all built-in palettes.
+    pub fn builtin_names() -> impl Iterator<Item = &'static str> {
+        data::BUILTINS.iter().map(|palette| palette.name)
+    }
+
+    /// Returns all built-in palettes.
+    pub fn builtins() -> impl Iterator<Item = Palette> {
+        data::BUILTINS.iter()
+            .map(|palette| Palette::new(palette.name, palette.colors.to_vec()))
+    }
+
+    /// Renders the palette as a row of truecolor swatches `width` cells wide.
+    pub fn preview(&self, width: usize) -> String {
+        self.colors.iter()
+            .map(|color| color.to_ansi_color_cells(width))
+            .collect()
+    }
+
+    /// Returns the palette in the given format.
+    pub fn export(&self, format: ExportFormat) -> String {
+        let mut out = String::new();
+        self.write_export(&mut out, format)
+            .expect("writing to a String cannot fail");
+        out
+    }
+
+    /// Writes the palette in the given format.
+    ///
+    /// Formats with a notion of categorical colors get one: ParaView presets
+    /// use indexed lookup, gnuplot gets one `set linetype` per color and GMT
+    /// a categorical CPT keyed by index.
+    pub fn write_export(&self, out: &mut impl Write, format: ExportFormat) -> fmt::Result {
+        match format {
+            ExportFormat::Matplotlib => {
+                let ident = identifier(&self.name);
+                writeln!(out, "from matplotlib.colors import ListedColormap\n")?;
+                writeln!(out, "{}_data = [", ident)?;
+                for color in &self.colors {
+                    let [r, g, b] = unit(*color);
+                    writeln!(out, "    [{:.6}, {:.6}, {:.6}],", r, g, b)?;
+                }
+                writeln!(out, "]\n")?;
+                writeln!(out, "{} = ListedColormap({}_data, name=\"{}\")",
+                    ident, ident, escape_json(&self.name))?;
+            },
+            ExportFormat::ParaviewXml => {
+                writeln!(out, "<ColorMaps>")?;
+                writeln!(out, "  <ColorMap space=\"RGB\" indexedLookup=\"true\" name=\"{}\">",
+                    escape_xml(&self.name))?;
+                for (i, color) in self.colors.iter().enumerate() {
+                    let [r, g, b] = unit(*color);
+                    writeln!(out, "    <Point x=\"{}\" o=\"1\" r=\"{:.6}\" g=\"{:.6}\" b=\"{:.6}\"/>",
+                        i, r, g, b)?;
+                }
+                writeln!(out, "  </ColorMap>")?;
+                writeln!(out, "</ColorMaps>")?;
+            },
+            ExportFormat::ParaviewJson => {
+                let colors: Vec<String> = self.colors.iter()
+                    .map(|color| {
+                        let [r, g, b] = unit(*color);
+                        format!("      {:.6}, {:.6}, {:.6}", r, g, b)
+                    })
+                    .collect();
+                let annotations: Vec<String> = (0..self.colors.len())
+                    .map(|i| format!("      \"{}\", \"{}\"", i, i))
+                    .collect();
+                writeln!(out, "[")?;
+                writeln!(out, "  {{")?;
+                writeln!(out, "    \"Name\" : \"{}\",", escape_json(&self.name))?;
+                writeln!(out, "    \"IndexedColors\" : [\n{}\n    ],", colors.join(",\n"))?;
+                writeln!(out, "    \"Annotations\" : [\n{}\n    ]", annotations.join(",\n"))?;
+                writeln!(out, "  }}")?;
+                writeln!(out, "]")?;
+            },
+            ExportFormat::Gnuplot => {
+                writeln!(out, "# {}", comment(&self.name))?;
+                for (i, color) in self.colors.iter().enumerate() {
+                    writeln!(out, "set linetype {} lc rgb '{}'", i + 1, color.to_hex())?;
+                }
+                writeln!(out, "set linetype cycle {}", self.colors.len())?;
+            },
+            ExportFormat::Gmt => {
+                writeln!(out, "# {}", comment(&self.name))?;
+                writeln!(out, "# COLOR_MODEL = RGB")?;
+                for (i, color) in self.colors.iter().enumerate() {
+                    writeln!(out, "{}\t{}/{}/{}", i, color.r, color.g, color.b)?;
+                }
+                writeln!(out, "N\t128/128/128")?;
+            },
+            ExportFormat::Ncl => {
+                writeln!(out, "ncolors={}", self.colors.len())?;
+                writeln!(out, "# r   g   b")?;
+                for color in &self.colors {
+                    writeln!(out, "{:<3} {:<3} {}", color.r, color.g, color.b)?;
+                }
+            },
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Palette {
+    type Err = String;
+
+    /// Looks up a built-in palette by name.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Palette::builtin(s).ok_or_else(|| format!("Unknown palette '{}'", s.trim()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtins_have_published_sizes() {
+        let sizes = [
+            ("okabe-ito", 9), ("tol-bright", 7), ("tol-vibrant", 7), ("tol-muted", 9),
+            ("tol-high-contrast", 3), ("tol-light", 9), ("set1", 9), ("set2", 8), ("set3", 12),
+            ("dark2", 8), ("paired", 12), ("pastel1", 9), ("pastel2", 8), ("accent", 8),
+            ("tableau10", 10), ("tab10", 10), ("tab20", 20),
+        ];
+        assert_eq!(Palette::builtin_names().collect::<Vec<_>>(), sizes.map(|(name, _)| name));
+        for (name, size) in sizes {
+            assert_eq!(Palette::builtin(name).unwrap().colors.len(), size, "{}", name);
+        }
+    }
+
+    #[test]
+    fn looks_up_builtins_by_name() {
+        let okabe_ito: Palette = " Okabe-Ito ".parse().unwrap();
+        assert_eq!(okabe_ito.name, "okabe-ito");
+        assert_eq!(okabe_ito.colors[..3], [RGB::new(0, 0, 0), RGB::new(0xE6, 0x9F, 0x00), RGB::new(0x56, 0xB4, 0xE9)]);
+        assert_eq!("viridis".parse::<Palette>(), Err("Unknown palette 'viridis'".to_string()));
+    }
+
+    #[test]
+    fn first_keeps_leading_colors() {
+        let tab10 = Palette::builtin("tab10").unwrap();
+        assert_eq!(tab10.clone().first(3).colors, tab10.colors[..3]);
+        assert_eq!(tab10.clone().first(50).colors, tab10.colors);
+    }
+
+    #[test]
+    fn exports_are_categorical() {
+        let palette = Palette::new("pair", vec![RGB::new(255, 0, 0), RGB::new(0, 0, 255)]);
+        assert_eq!(palette.export(ExportFormat::Gnuplot),
+            "# pair\nset linetype 1 lc rgb '#FF0000'\nset linetype 2 lc rgb '#0000FF'\nset linetype cycle 2\n");
+        assert_eq!(palette.export(ExportFormat::Gmt),
+            "# pair\n# COLOR_MODEL = RGB\n0\t255/0/0\n1\t0/0/255\nN\t128/128/128\n");
+        assert_eq!(palette.export(ExportFormat::Ncl), "ncolors=2\n# r   g   b\n255 0   0\n0   0   255\n");
+        assert!(palette.export(ExportFormat::ParaviewXml).contains("indexedLookup=\"true\""));
+        assert!(palette.export(ExportFormat::ParaviewJson).contains("\"IndexedColors\""));
+    }
+}
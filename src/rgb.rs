@@ -0,0 +1,188 @@
+use std::str::FromStr;
+
+use crate::linear::srgb_to_linear;
+use crate::{LinearRGB, ParseColorError, HSL, RGBA, XYZ};
+
+/// The color model in which hue rotations for harmonies are performed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HueSpace {
+    /// Rotate the HSL hue. Fast, but lightness is not preserved perceptually.
+    #[default]
+    Hsl,
+    /// Rotate the Oklch hue, keeping perceptual lightness and mapping the
+    /// result back into gamut by reducing chroma.
+    Oklch,
+}
+
+impl FromStr for HueSpace {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "hsl" => Ok(HueSpace::Hsl),
+            "oklch" => Ok(HueSpace::Oklch),
+            _ => Err(format!("Unknown hue space '{}'", s)),
+        }
+    }
+}
+
+/// An 8-bit per channel sRGB color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RGB {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl RGB {
+    /// Creates a new RGB color from its components.
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        RGB { r, g, b }
+    }
+
+    /// Formats the color as an uppercase `#RRGGBB` hex string.
+    pub fn to_hex(self) -> String {
+        format!("#{:02X}{:02X}{:02X}", self.r, self.g, self.b)
+    }
+
+    /// Returns a truecolor ANSI escape sequence rendering a swatch of this color.
+    pub fn to_ansi_color_block(self) -> String {
+        // Create a wider color block using RGB background color
+        format!("\x1b[48;2;{};{};{}m        \x1b[0m", self.r, self.g, self.b)
+    }
+
+    /// Returns a truecolor swatch `width` terminal cells wide.
+    pub fn to_ansi_color_cells(self, width: usize) -> String {
+        format!("\x1b[48;2;{};{};{}m{}\x1b[0m", self.r, self.g, self.b, " ".repeat(width))
+    }
+
+    /// Returns `text` drawn in this color on a truecolor `background`.
+    pub fn to_ansi_text_on(self, background: RGB, text: &str) -> String {
+        format!("\x1b[38;2;{};{};{};48;2;{};{};{}m{}\x1b[0m",
+            self.r, self.g, self.b, background.r, background.g, background.b, text)
+    }
+
+    /// Returns a swatch followed by the hex code.
+    pub fn display_with_color(&self) -> String {
+        format!("{} {}", self.to_ansi_color_block(), self.to_hex())
+    }
+
+    /// Converts to the HSL color model.
+    pub fn to_hsl(self) -> HSL {
+        let r = self.r as f64 / 255.0;
+        let g = self.g as f64 / 255.0;
+        let b = self.b as f64 / 255.0;
+
+        let max = r.max(g.max(b));
+        let min = r.min(g.min(b));
+        let delta = max - min;
+
+        let mut h = 0.0;
+        let mut s = 0.0;
+        let l = (max + min) / 2.0;
+
+        if delta != 0.0 {
+            s = if l < 0.5 {
+                delta / (max + min)
+            } else {
+                delta / (2.0 - max - min)
+            };
+
+            h = match max {
+                x if x == r => ((g - b) / delta) + (if g < b { 6.0 } else { 0.0 }),
+                x if x == g => ((b - r) / delta) + 2.0,
+                _ => ((r - g) / delta) + 4.0,
+            };
+
+            h *= 60.0;
+        }
+
+        HSL {
+            h,
+            s: s * 100.0,
+            l: l * 100.0,
+        }
+    }
+
+    /// Decodes the sRGB transfer function to linear light.
+    pub fn to_linear(self) -> LinearRGB {
+        LinearRGB {
+            r: srgb_to_linear(self.r as f64 / 255.0),
+            g: srgb_to_linear(self.g as f64 / 255.0),
+            b: srgb_to_linear(self.b as f64 / 255.0),
+        }
+    }
+
+    /// Converts to CIE XYZ (D65) via linear RGB.
+    pub fn to_xyz(self) -> XYZ {
+        self.to_linear().to_xyz()
+    }
+
+    /// Returns the complementary color by inverting each channel.
+    pub fn complement(&self) -> RGB {
+        RGB {
+            r: 255 - self.r,
+            g: 255 - self.g,
+            b: 255 - self.b,
+        }
+    }
+
+    /// Rotates the HSL hue by `degrees`.
+    pub fn rotate_hue(&self, degrees: f64) -> RGB {
+        self.rotate_hue_in(HueSpace::Hsl, degrees)
+    }
+
+    /// Rotates the hue by `degrees` in the given color model.
+    pub fn rotate_hue_in(&self, space: HueSpace, degrees: f64) -> RGB {
+        match space {
+            HueSpace::Hsl => {
+                let mut hsl = self.to_hsl();
+                hsl.h = (hsl.h + degrees) % 360.0;
+                hsl.to_rgb()
+            },
+            HueSpace::Oklch => {
+                let mut lch = self.to_oklch();
+                lch.h = (lch.h + degrees).rem_euclid(360.0);
+                lch.to_rgb_in_gamut()
+            },
+        }
+    }
+
+    /// Returns the color and the two colors 120° apart in HSL hue.
+    pub fn triads(&self) -> Vec<RGB> {
+        self.triads_in(HueSpace::Hsl)
+    }
+
+    /// Returns the color and the two colors 120° apart in hue.
+    pub fn triads_in(&self, space: HueSpace) -> Vec<RGB> {
+        vec![
+            *self,
+            self.rotate_hue_in(space, 120.0),
+            self.rotate_hue_in(space, 240.0),
+        ]
+    }
+
+    /// Returns the color and the three colors 90° apart in HSL hue.
+    pub fn tetrads(&self) -> Vec<RGB> {
+        self.tetrads_in(HueSpace::Hsl)
+    }
+
+    /// Returns the color and the three colors 90° apart in hue.
+    pub fn tetrads_in(&self, space: HueSpace) -> Vec<RGB> {
+        vec![
+            *self,
+            self.rotate_hue_in(space, 90.0),
+            self.rotate_hue_in(space, 180.0),
+            self.rotate_hue_in(space, 270.0),
+        ]
+    }
+}
+
+impl FromStr for RGB {
+    type Err = ParseColorError;
+
+    /// Parses any format accepted by [`RGBA`], discarding alpha.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<RGBA>().map(RGBA::rgb)
+    }
+}
@@ -0,0 +1,167 @@
+use std::str::FromStr;
+
+use crate::{css, named};
+use crate::{ParseColorError, ParseErrorKind, RGB};
+
+/// An sRGB color with an alpha channel.
+///
+/// Alpha is a coverage fraction in `[0, 1]`, where `1` is fully opaque.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RGBA {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: f64,
+}
+
+impl RGBA {
+    /// Creates a new RGBA color from its components.
+    pub fn new(r: u8, g: u8, b: u8, a: f64) -> Self {
+        RGBA { r, g, b, a: a.clamp(0.0, 1.0) }
+    }
+
+    /// Returns the color channels without alpha.
+    pub fn rgb(self) -> RGB {
+        RGB { r: self.r, g: self.g, b: self.b }
+    }
+
+    /// Returns true if the color is fully opaque.
+    pub fn is_opaque(self) -> bool {
+        self.a >= 1.0
+    }
+
+    /// Formats the color as `#RRGGBB`, or `#RRGGBBAA` when not fully opaque.
+    pub fn to_hex(self) -> String {
+        if self.is_opaque() {
+            self.rgb().to_hex()
+        } else {
+            format!("{}{:02X}", self.rgb().to_hex(), (self.a * 255.0).round() as u8)
+        }
+    }
+
+    /// Composites the color over an opaque background.
+    pub fn over(self, background: RGB) -> RGB {
+        let blend = |fg: u8, bg: u8| {
+            (fg as f64 * self.a + bg as f64 * (1.0 - self.a)).round() as u8
+        };
+        RGB {
+            r: blend(self.r, background.r),
+            g: blend(self.g, background.g),
+            b: blend(self.b, background.b),
+        }
+    }
+}
+
+impl From<RGB> for RGBA {
+    fn from(rgb: RGB) -> Self {
+        RGBA { r: rgb.r, g: rgb.g, b: rgb.b, a: 1.0 }
+    }
+}
+
+impl From<RGBA> for RGB {
+    fn from(rgba: RGBA) -> Self {
+        rgba.rgb()
+    }
+}
+
+/// Parses 3, 4, 6 or 8 hex digits (without the leading `#`).
+fn parse_hex(input: &str, hex: &str) -> Result<RGBA, ParseColorError> {
+    if let Some((i, c)) = hex.char_indices().find(|(_, c)| !c.is_ascii_hexdigit()) {
+        let digit = &hex[i..i + c.len_utf8()];
+        return Err(ParseColorError::at(ParseErrorKind::InvalidDigit(c), input, digit));
+    }
+
+    let digits: Vec<u8> = hex.chars()
+        .map(|c| c.to_digit(16).unwrap() as u8)
+        .collect();
+    let (r, g, b, a) = match digits[..] {
+        [r, g, b] => (r * 17, g * 17, b * 17, 255),
+        [r, g, b, a] => (r * 17, g * 17, b * 17, a * 17),
+        [r1, r2, g1, g2, b1, b2] => (r1 << 4 | r2, g1 << 4 | g2, b1 << 4 | b2, 255),
+        [r1, r2, g1, g2, b1, b2, a1, a2] => {
+            (r1 << 4 | r2, g1 << 4 | g2, b1 << 4 | b2, a1 << 4 | a2)
+        },
+        _ => {
+            let kind = ParseErrorKind::InvalidLength {
+                expected: "3, 4, 6 or 8 hex digits",
+                found: digits.len(),
+            };
+            return Err(ParseColorError::at(kind, input, hex));
+        },
+    };
+
+    Ok(RGBA { r, g, b, a: a as f64 / 255.0 })
+}
+
+/// Parses one `r,g,b` channel, distinguishing malformed from out-of-range values.
+fn parse_channel(input: &str, part: &str, component: &'static str) -> Result<u8, ParseColorError> {
+    part.parse().map_err(|_| {
+        let kind = match part.parse::<f64>() {
+            Ok(_) => ParseErrorKind::OutOfRange(component),
+            Err(_) => ParseErrorKind::InvalidComponent(component),
+        };
+        ParseColorError::at(kind, input, part)
+    })
+}
+
+impl FromStr for RGBA {
+    type Err = ParseColorError;
+
+    /// Parses hex (`#RGB`, `#RGBA`, `#RRGGBB`, `#RRGGBBAA`, with or without
+    /// `#`), comma separated `r,g,b` or `r,g,b,a`, a color name (see
+    /// [`named`](crate::named)), or any CSS Color 4 functional notation.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let s = input.trim();
+
+        if s.is_empty() {
+            return Err(ParseColorError::new(ParseErrorKind::Empty, 0..input.len()));
+        }
+
+        if s.contains('(') {
+            let (rgb, a) = css::parse_color(input)?;
+            return Ok(RGBA { r: rgb.r, g: rgb.g, b: rgb.b, a });
+        }
+
+        // Handle hex format (with or without #)
+        if let Some(hex) = s.strip_prefix('#') {
+            return parse_hex(input, hex);
+        }
+        if s.chars().all(|c| c.is_ascii_hexdigit()) {
+            return parse_hex(input, s);
+        }
+
+        if s.eq_ignore_ascii_case("transparent") {
+            return Ok(RGBA { r: 0, g: 0, b: 0, a: 0.0 });
+        }
+        if s.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            return named::lookup(s).map(RGBA::from).ok_or_else(|| {
+                ParseColorError::at(ParseErrorKind::UnknownName(s.to_string()), input, s)
+            });
+        }
+
+        // Parse RGB format (r,g,b) with optional alpha
+        let parts: Vec<&str> = s.split(',').map(str::trim).collect();
+        if parts.len() == 1 {
+            let reason = "Unrecognized color format. Expected hex, r,g,b, a name or a CSS function";
+            let kind = ParseErrorKind::UnsupportedSyntax(reason.to_string());
+            return Err(ParseColorError::at(kind, input, s));
+        }
+        if parts.len() != 3 && parts.len() != 4 {
+            let kind = ParseErrorKind::InvalidLength {
+                expected: "r,g,b or r,g,b,a",
+                found: parts.len(),
+            };
+            return Err(ParseColorError::at(kind, input, s));
+        }
+
+        let r = parse_channel(input, parts[0], "red")?;
+        let g = parse_channel(input, parts[1], "green")?;
+        let b = parse_channel(input, parts[2], "blue")?;
+        let a = match parts.get(3) {
+            Some(a) => css::number(input, a, 1.0, "alpha")?.clamp(0.0, 1.0),
+            None => 1.0,
+        };
+
+        Ok(RGBA { r, g, b, a })
+    }
+}
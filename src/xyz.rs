@@ -0,0 +1,215 @@
+use crate::{LinearRGB, RGB};
+
+/// A color in the CIE 1931 XYZ space, scaled so that the reference white has
+/// `Y = 1`.
+///
+/// Conversions to and from RGB use the sRGB primaries with a D65 white point.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct XYZ {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl XYZ {
+    /// Creates a new XYZ color.
+    pub fn new(x: f64, y: f64, z: f64) -> Self {
+        XYZ { x, y, z }
+    }
+
+    /// Converts to linear-light sRGB.
+    pub fn to_linear(self) -> LinearRGB {
+        LinearRGB {
+            r: 3.2404542 * self.x - 1.5371385 * self.y - 0.4985314 * self.z,
+            g: -0.9692660 * self.x + 1.8760108 * self.y + 0.0415560 * self.z,
+            b: 0.0556434 * self.x - 0.2040259 * self.y + 1.0572252 * self.z,
+        }
+    }
+
+    /// Converts to 8-bit sRGB, clipping out-of-gamut values.
+    pub fn to_rgb(self) -> RGB {
+        self.to_linear().to_rgb()
+    }
+}
+
+impl LinearRGB {
+    /// Converts to CIE XYZ (D65).
+    pub fn to_xyz(self) -> XYZ {
+        XYZ {
+            x: 0.4124564 * self.r + 0.3575761 * self.g + 0.1804375 * self.b,
+            y: 0.2126729 * self.r + 0.7151522 * self.g + 0.0721750 * self.b,
+            z: 0.0193339 * self.r + 0.1191920 * self.g + 0.9503041 * self.b,
+        }
+    }
+}
+
+impl From<LinearRGB> for XYZ {
+    fn from(linear: LinearRGB) -> Self {
+        linear.to_xyz()
+    }
+}
+
+impl From<XYZ> for LinearRGB {
+    fn from(xyz: XYZ) -> Self {
+        xyz.to_linear()
+    }
+}
+
+impl From<RGB> for XYZ {
+    fn from(rgb: RGB) -> Self {
+        rgb.to_xyz()
+    }
+}
+
+impl From<XYZ> for RGB {
+    fn from(xyz: XYZ) -> Self {
+        xyz.to_rgb()
+    }
+}
+
+/// A CIE standard illuminant used as the reference white of a color space.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WhitePoint {
+    /// Horizon light, used by ICC profiles and print workflows.
+    D50,
+    /// Mid-morning daylight.
+    D55,
+    /// Noon daylight, the white point of sRGB.
+    #[default]
+    D65,
+    /// North sky daylight.
+    D75,
+    /// Incandescent tungsten.
+    A,
+    /// Equal energy.
+    E,
+}
+
+impl WhitePoint {
+    /// Returns the tristimulus values of the 2° observer, normalized to `Y = 1`.
+    pub fn xyz(self) -> XYZ {
+        match self {
+            WhitePoint::D50 => XYZ::new(0.96422, 1.0, 0.82521),
+            WhitePoint::D55 => XYZ::new(0.95682, 1.0, 0.92149),
+            WhitePoint::D65 => XYZ::new(0.95047, 1.0, 1.08883),
+            WhitePoint::D75 => XYZ::new(0.94972, 1.0, 1.22638),
+            WhitePoint::A => XYZ::new(1.09850, 1.0, 0.35585),
+            WhitePoint::E => XYZ::new(1.0, 1.0, 1.0),
+        }
+    }
+}
+
+impl std::str::FromStr for WhitePoint {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "d50" => Ok(WhitePoint::D50),
+            "d55" => Ok(WhitePoint::D55),
+            "d65" => Ok(WhitePoint::D65),
+            "d75" => Ok(WhitePoint::D75),
+            "a" => Ok(WhitePoint::A),
+            "e" => Ok(WhitePoint::E),
+            _ => Err(format!("Unknown white point '{}'", s)),
+        }
+    }
+}
+
+// Bradford cone response matrix and its inverse.
+const BRADFORD: [[f64; 3]; 3] = [
+    [0.8951, 0.2664, -0.1614],
+    [-0.7502, 1.7135, 0.0367],
+    [0.0389, -0.0685, 1.0296],
+];
+const BRADFORD_INV: [[f64; 3]; 3] = [
+    [0.9869929, -0.1470543, 0.1599627],
+    [0.4323053, 0.5183603, 0.0492912],
+    [-0.0085287, 0.0400428, 0.9684867],
+];
+
+/// Multiplies the vector `v` by the matrix `m`.
+pub(crate) fn mul(m: &[[f64; 3]; 3], v: [f64; 3]) -> [f64; 3] {
+    [
+        m[0][0] * v[0] + m[0][1] * v[1] + m[0][2] * v[2],
+        m[1][0] * v[0] + m[1][1] * v[1] + m[1][2] * v[2],
+        m[2][0] * v[0] + m[2][1] * v[1] + m[2][2] * v[2],
+    ]
+}
+
+/// Interpolates linearly between `a` and `b`, reaching `b` at `f = 1`.
+pub(crate) fn lerp(a: [f64; 3], b: [f64; 3], f: f64) -> [f64; 3] {
+    [0, 1, 2].map(|i| a[i] + (b[i] - a[i]) * f)
+}
+
+impl XYZ {
+    /// Chromatically adapts this color from one reference white to another
+    /// using the Bradford transform.
+    pub fn adapt(self, from: WhitePoint, to: WhitePoint) -> XYZ {
+        if from == to {
+            return self;
+        }
+
+        let src = mul(&BRADFORD, [from.xyz().x, from.xyz().y, from.xyz().z]);
+        let dst = mul(&BRADFORD, [to.xyz().x, to.xyz().y, to.xyz().z]);
+        let cone = mul(&BRADFORD, [self.x, self.y, self.z]);
+        let scaled = [
+            cone[0] * dst[0] / src[0],
+            cone[1] * dst[1] / src[1],
+            cone[2] * dst[2] / src[2],
+        ];
+        let [x, y, z] = mul(&BRADFORD_INV, scaled);
+        XYZ { x, y, z }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_xyz(actual: XYZ, expected: [f64; 3], tolerance: f64) {
+        let actual_array = [actual.x, actual.y, actual.z];
+        for (a, e) in actual_array.into_iter().zip(expected) {
+            assert!((a - e).abs() < tolerance, "{:?} != {:?}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn srgb_primaries_match_reference_xyz() {
+        // Bruce Lindbloom's sRGB to XYZ (D65) matrix, to four places
+        let cases = [
+            (RGB::new(255, 0, 0), [0.4125, 0.2127, 0.0193]),
+            (RGB::new(0, 255, 0), [0.3576, 0.7152, 0.1192]),
+            (RGB::new(0, 0, 255), [0.1804, 0.0722, 0.9503]),
+            (RGB::new(255, 255, 255), [0.9505, 1.0, 1.0888]),
+            (RGB::new(0, 0, 0), [0.0, 0.0, 0.0]),
+        ];
+        for (rgb, expected) in cases {
+            assert_xyz(rgb.to_xyz(), expected, 1e-4);
+        }
+    }
+
+    #[test]
+    fn white_is_d65() {
+        let d65 = WhitePoint::D65.xyz();
+        assert_xyz(RGB::new(255, 255, 255).to_xyz(), [d65.x, d65.y, d65.z], 1e-6);
+    }
+
+    #[test]
+    fn xyz_round_trips_through_rgb() {
+        for v in (0..=255).step_by(15) {
+            for rgb in [RGB::new(v, 0, 0), RGB::new(0, v, 0), RGB::new(0, 0, v), RGB::new(v, 255 - v, v / 2)] {
+                assert_eq!(rgb.to_xyz().to_rgb(), rgb);
+            }
+        }
+    }
+
+    #[test]
+    fn bradford_maps_white_to_white() {
+        let d65 = WhitePoint::D65.xyz();
+        for white in [WhitePoint::D50, WhitePoint::D55, WhitePoint::D75, WhitePoint::A, WhitePoint::E] {
+            let w = white.xyz();
+            assert_xyz(d65.adapt(WhitePoint::D65, white), [w.x, w.y, w.z], 1e-4);
+            assert_xyz(w.adapt(white, WhitePoint::D65), [d65.x, d65.y, d65.z], 1e-4);
+        }
+    }
+}